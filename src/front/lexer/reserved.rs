@@ -23,6 +23,10 @@ pub enum ReservedWord {
     For,
     #[word("while")]
     While,
+    #[word("break")]
+    Break,
+    #[word("continue")]
+    Continue,
     #[word("let")]
     Let,
     #[word("const")]
@@ -47,6 +51,8 @@ pub enum ReservedWord {
     Struct,
     #[word("enumeration")]
     Enum,
+    #[word("as")]
+    As,
 }
 
 #[derive(Reserved, Debug, EnumIter, PartialEq, Clone)]
@@ -113,8 +119,14 @@ pub enum Separator {
     CloseBrackets,
     #[word(",")]
     Comma,
+    #[word("::")]
+    DoubleColon,
+    #[word("..=")]
+    InclusiveRange,
     #[word(".")]
     Dot,
+    #[word("#")]
+    Hash,
     #[word("\n")]
     NewLine,
     #[word(" ")]
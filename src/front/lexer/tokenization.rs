@@ -11,8 +11,15 @@ pub type LexicalError = String;
 /// Tuple: (line number, raw token)
 pub type RawToken<'a> = (u32, &'a str);
 
+/// Line and column, both starting at 1, of the first character of a token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Location {
+    pub line: u32,
+    pub column: u32,
+}
+
 pub trait Splitter {
-    fn split_code(&self) -> Vec<Option<RawToken>>;
+    fn split_code(&self) -> Vec<Option<RawToken<'_>>>;
     fn split_and_parse_jp_numerals<T>(&self) -> Result<T, &Self>
     where
         T: str::FromStr + Copy + PartialEq + PartialOrd;
@@ -21,9 +28,9 @@ pub trait Splitter {
 
 impl Splitter for str {
     /// Splits the expected code as a &str to all Separators and Operators
-    fn split_code(&self) -> Vec<Option<RawToken>> {
+    fn split_code(&self) -> Vec<Option<RawToken<'_>>> {
         let re = Regex::new(&format!(
-            r#"\/\*[\S\s]*\*\/|//.*|(?:(?:[0-9])[.](?:[0-9]*)|"[\S\s]*"|'[\S\s]'|{separators_and_operators})"#,
+            r#"\/\*[\S\s]*\*\/|//.*|(?:(?:[0-9])[.](?:[0-9]+)|"[^"]*"|'[\S\s]'|{separators_and_operators})"#,
             separators_and_operators = {
                 Separator::iter()
                     .map(|separator| regex::escape(&separator.to_string()))
@@ -158,3 +165,21 @@ pub fn tokenize(source_code_contents: &str) -> Tokens {
         })
         .collect::<Tokens>()
 }
+
+/// Location of every token [`tokenize`] produces for the same source, in the same order.
+pub fn locate_tokens(source_code_contents: &str) -> Vec<Location> {
+    let start = source_code_contents.as_ptr() as usize;
+    source_code_contents
+        .split_code()
+        .iter()
+        .flatten()
+        .map(|(_, raw)| {
+            let preceding = &source_code_contents[..raw.as_ptr() as usize - start];
+            let line_start = preceding.rfind('\n').map_or(0, |newline| newline + 1);
+            Location {
+                line: preceding.matches('\n').count() as u32 + 1,
+                column: preceding[line_start..].chars().count() as u32 + 1,
+            }
+        })
+        .collect()
+}
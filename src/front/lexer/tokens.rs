@@ -98,6 +98,29 @@ impl TryFrom<String> for Literal {
     }
 }
 
+impl Literal {
+    /// Position of the literal on the number line, for the types that can form ranges.
+    pub fn ordinal(&self) -> Option<i64> {
+        match self {
+            Literal::Int(int) => Some(*int as i64),
+            Literal::Long(long) => Some(*long),
+            Literal::Char(char) => Some(char.content as i64),
+            _ => None,
+        }
+    }
+
+    /// Value of a numeric literal, so that `1` and `1.0` compare equal.
+    pub fn numeric_value(&self) -> Option<f64> {
+        match self {
+            Literal::Int(int) => Some(f64::from(*int)),
+            Literal::Long(long) => Some(*long as f64),
+            Literal::Float(float) => Some(f64::from(*float)),
+            Literal::Double(double) => Some(*double),
+            _ => None,
+        }
+    }
+}
+
 impl Display for Literal {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
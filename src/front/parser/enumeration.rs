@@ -1,12 +1,21 @@
-use crate::front::lexer::reserved::{ReservedWord, Separator};
+use crate::front::lexer::reserved::{Operator, ReservedWord, Separator};
 use crate::front::lexer::tokens::Token;
-use crate::front::parser::grammar::id;
+use crate::front::parser::grammar::{id, literal, typed};
 use crate::front::parser::grammar::{NonTerminal, Parser, ParsingRule, Symbol, Terminal};
 
 pub struct Enumeration;
 
 impl Parser for Enumeration {
     /// <Enum> :: enum <Identifier> { <EnumBody> }
+    /// <EnumBody> :: id <EnumVariantData> <EnumBody> | , <EnumBody> | ε
+    /// <EnumVariantData> :: = <EnumDiscriminant> | ( <EnumTupleFields> ) | { <EnumStructFields> } | ε
+    /// <EnumDiscriminant> :: literal | - literal
+    /// <EnumTupleFields> :: <DataType> <TypeArgs> <EnumTupleFields>
+    ///                    | , <DataType> <TypeArgs> <EnumTupleFields>
+    ///                    | ε
+    /// <EnumStructFields> :: <DataType> <TypeArgs> id <EnumStructFields>
+    ///                     | , <DataType> <TypeArgs> id <EnumStructFields>
+    ///                     | ε
     const PARSING_TABLE: &'static [ParsingRule<'_>] = &[
         ParsingRule {
             non_terminal: NonTerminal::Enum,
@@ -29,6 +38,7 @@ impl Parser for Enumeration {
             token: id,
             production: &[
                 Symbol::Terminal(id),
+                Symbol::NonTerminal(NonTerminal::EnumVariantData),
                 Symbol::NonTerminal(NonTerminal::EnumBody),
             ],
         },
@@ -45,5 +55,107 @@ impl Parser for Enumeration {
             token: Terminal::Token(Token::Separator(Separator::CloseCurlyBraces)),
             production: &[Symbol::Terminal(Terminal::Epsilon)],
         },
+        ParsingRule {
+            non_terminal: NonTerminal::EnumVariantData,
+            token: Terminal::Token(Token::Operator(Operator::Assignment)),
+            production: &[
+                Symbol::Terminal(Terminal::Token(Token::Operator(Operator::Assignment))),
+                Symbol::NonTerminal(NonTerminal::EnumDiscriminant),
+            ],
+        },
+        ParsingRule {
+            non_terminal: NonTerminal::EnumVariantData,
+            token: Terminal::Token(Token::Separator(Separator::OpenParenthesis)),
+            production: &[
+                Symbol::Terminal(Terminal::Token(Token::Separator(
+                    Separator::OpenParenthesis,
+                ))),
+                Symbol::NonTerminal(NonTerminal::EnumTupleFields),
+                Symbol::Terminal(Terminal::Token(Token::Separator(
+                    Separator::CloseParenthesis,
+                ))),
+            ],
+        },
+        ParsingRule {
+            non_terminal: NonTerminal::EnumVariantData,
+            token: Terminal::Token(Token::Separator(Separator::OpenCurlyBraces)),
+            production: &[
+                Symbol::Terminal(Terminal::Token(Token::Separator(
+                    Separator::OpenCurlyBraces,
+                ))),
+                Symbol::NonTerminal(NonTerminal::EnumStructFields),
+                Symbol::Terminal(Terminal::Token(Token::Separator(
+                    Separator::CloseCurlyBraces,
+                ))),
+            ],
+        },
+        ParsingRule {
+            non_terminal: NonTerminal::EnumVariantData,
+            token: Terminal::Any,
+            production: &[Symbol::Terminal(Terminal::Epsilon)],
+        },
+        ParsingRule {
+            non_terminal: NonTerminal::EnumDiscriminant,
+            token: literal,
+            production: &[Symbol::Terminal(literal)],
+        },
+        ParsingRule {
+            non_terminal: NonTerminal::EnumDiscriminant,
+            token: Terminal::Token(Token::Operator(Operator::Subtraction)),
+            production: &[
+                Symbol::Terminal(Terminal::Token(Token::Operator(Operator::Subtraction))),
+                Symbol::Terminal(literal),
+            ],
+        },
+        ParsingRule {
+            non_terminal: NonTerminal::EnumTupleFields,
+            token: typed,
+            production: &[
+                Symbol::Terminal(typed),
+                Symbol::NonTerminal(NonTerminal::TypeArgs),
+                Symbol::NonTerminal(NonTerminal::EnumTupleFields),
+            ],
+        },
+        ParsingRule {
+            non_terminal: NonTerminal::EnumTupleFields,
+            token: Terminal::Token(Token::Separator(Separator::Comma)),
+            production: &[
+                Symbol::Terminal(Terminal::Token(Token::Separator(Separator::Comma))),
+                Symbol::Terminal(typed),
+                Symbol::NonTerminal(NonTerminal::TypeArgs),
+                Symbol::NonTerminal(NonTerminal::EnumTupleFields),
+            ],
+        },
+        ParsingRule {
+            non_terminal: NonTerminal::EnumTupleFields,
+            token: Terminal::Token(Token::Separator(Separator::CloseParenthesis)),
+            production: &[Symbol::Terminal(Terminal::Epsilon)],
+        },
+        ParsingRule {
+            non_terminal: NonTerminal::EnumStructFields,
+            token: typed,
+            production: &[
+                Symbol::Terminal(typed),
+                Symbol::NonTerminal(NonTerminal::TypeArgs),
+                Symbol::Terminal(id),
+                Symbol::NonTerminal(NonTerminal::EnumStructFields),
+            ],
+        },
+        ParsingRule {
+            non_terminal: NonTerminal::EnumStructFields,
+            token: Terminal::Token(Token::Separator(Separator::Comma)),
+            production: &[
+                Symbol::Terminal(Terminal::Token(Token::Separator(Separator::Comma))),
+                Symbol::Terminal(typed),
+                Symbol::NonTerminal(NonTerminal::TypeArgs),
+                Symbol::Terminal(id),
+                Symbol::NonTerminal(NonTerminal::EnumStructFields),
+            ],
+        },
+        ParsingRule {
+            non_terminal: NonTerminal::EnumStructFields,
+            token: Terminal::Token(Token::Separator(Separator::CloseCurlyBraces)),
+            production: &[Symbol::Terminal(Terminal::Epsilon)],
+        },
     ];
 }
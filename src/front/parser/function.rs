@@ -1,26 +1,46 @@
 use crate::front::lexer::reserved::{Operator, ReservedWord, Separator};
 use crate::front::lexer::tokens::Token;
-use crate::front::parser::grammar::{id, literal, typed, unary_op, NonTerminal, Parser, ParsingRule, Symbol, Terminal};
+use crate::front::parser::grammar::{
+    id, literal, reassign_op, typed, unary_op, NonTerminal, Parser, ParsingRule, Symbol, Terminal,
+};
 
 // todo arraydecl
 
 pub struct Function;
 
 impl Parser for Function {
-    /// <Func> :: func <DataType> id ( <FuncArgument> ) { <FuncBody> } <S>
-    /// <FuncArgument> :: <DataType> id <FuncArgument> | , <DataType> id <FuncArgument> | e
+    /// <Func> :: func <DataType> <TypeArgs> id <TypeParams> ( <FuncArgument> ) { <FuncBody> } <S>
+    /// <FuncArgument> :: <DataType> <TypeArgs> id <FuncArgument>
+    ///                 | , <DataType> <TypeArgs> id <FuncArgument>
+    ///                 | e
+    /// <FuncAttribute> :: # [ id ( <AttributeLint> ) ] <FuncAttribute> | ε
+    /// <AttributeLint> :: id <AttributeLintList>
+    /// <AttributeLintList> :: , id <AttributeLintList> | ε
     /// <FuncBody> :: <StmntList> | ε
     /// <StmntList> :: <Statement> <StmntList> | ε
-    /// <Statement> :: return <Expr> ;
+    /// <Statement> :: return <StmntReturnValue> ;
+    ///             | break ;
+    ///             | continue ;
     ///             | if ( <Expr> ) { <StmntList> } <StmntElse>
     ///             | match ( <Expr> ) { <StmntCase> }
     ///             | for ( <StmntDecl> ; <Expr> ; <StmntAssign> ) { <StmntList> }
     ///             | while ( <Expr> ) { <StmntList> }
     ///             | <StmntDecl> ;
     ///             | <StmntAssign> ;
-    /// <StmntCase> :: case <Literal> { <StmntList> } <StmntCase> | default { <StmntList> } | ε
-    /// <Expr> :: <ExprOperand> <ExprOperation>
-    /// <ExprOperand> :: id <ExprCall> | literal
+    ///             | { <StmntList> }
+    /// <StmntReturnValue> :: <Expr> | ε
+    /// <StmntCase> :: case <CasePattern> <CasePatternList> { <StmntList> } <StmntCase>
+    ///             | default { <StmntList> }
+    ///             | ε
+    /// <CasePattern> :: literal <CaseRange> | id <CaseVariant> <CaseBindings>
+    /// <CasePatternList> :: , <CasePattern> <CasePatternList> | ε
+    /// <CaseRange> :: ..= literal | ε
+    /// <CaseVariant> :: :: id | ε
+    /// <CaseBindings> :: ( <CaseBindingList> ) | ε
+    /// <CaseBindingList> :: id <CaseBindingList> | , id <CaseBindingList> | ε
+    /// <Expr> :: <ExprOperand> <ExprCast> <ExprOperation>
+    /// <ExprOperand> :: id <ExprCall> | literal | ( <Expr> ) | ! <ExprOperand> | - <ExprOperand>
+    /// <ExprCast> :: as <DataType> <ExprCast> | ε
     /// <ExprCall> :: <ExprFuncCall> <ExprCall>
     ///             | <ExprArrayAccess> <ExprCall>
     ///             | <ExprFieldAccess> <ExprCall>
@@ -34,11 +54,15 @@ impl Parser for Function {
     /// <StmntElse> :: elif ( <Expr> ) { <StmntList> } <StmntElse>
     ///              | else { <StmntList> }
     ///              | ε
-    /// <StmntDecl> :: let <DataType> id = <Expr> | const <DataType> id = <Expr>
-    /// <StmntAssign> :: id = <ExprCall> <ReassignOp> <Expr>
+    /// <StmntDecl> :: let <StmntDeclType> | const <StmntDeclType>
+    /// <StmntDeclType> :: id <StmntDeclName> | <DataType> id <StmntDeclValue>
+    /// <StmntDeclName> :: id <StmntDeclValue> | <TypeArgs> id <StmntDeclValue> | = <Expr>
+    /// <StmntDeclValue> :: = <Expr> | ε
+    /// <StmntAssign> :: id <ExprCall> <StmntAssignValue>
+    /// <StmntAssignValue> :: <ReassignOp> <Expr> | ε
     const PARSING_TABLE: &'static [ParsingRule<'_>] = &[
         /*
-            <Func> :: func <DataType> id ( <FuncArgument> ) { <FuncBody> } <S>
+            <Func> :: func <DataType> <TypeArgs> id <TypeParams> ( <FuncArgument> ) { <FuncBody> } <S>
         */
         ParsingRule {
             non_terminal: NonTerminal::Func,
@@ -46,7 +70,9 @@ impl Parser for Function {
             production: &[
                 Symbol::Terminal(Terminal::Token(Token::ReservedWord(ReservedWord::Function))),
                 Symbol::Terminal(typed),
+                Symbol::NonTerminal(NonTerminal::TypeArgs),
                 Symbol::Terminal(id),
+                Symbol::NonTerminal(NonTerminal::TypeParams),
                 Symbol::Terminal(Terminal::Token(Token::Separator(
                     Separator::OpenParenthesis,
                 ))),
@@ -66,7 +92,69 @@ impl Parser for Function {
         },
         /*
 
-            <FuncArgument> :: <DataType> id <FuncArgument> | , <DataType> id <FuncArgument> | e
+            <FuncAttribute> :: # [ id ( <AttributeLint> ) ] <FuncAttribute> | e
+
+        */
+        ParsingRule {
+            non_terminal: NonTerminal::FuncAttribute,
+            token: Terminal::Token(Token::Separator(Separator::Hash)),
+            production: &[
+                Symbol::Terminal(Terminal::Token(Token::Separator(Separator::Hash))),
+                Symbol::Terminal(Terminal::Token(Token::Separator(Separator::OpenBrackets))),
+                Symbol::Terminal(id),
+                Symbol::Terminal(Terminal::Token(Token::Separator(
+                    Separator::OpenParenthesis,
+                ))),
+                Symbol::NonTerminal(NonTerminal::AttributeLint),
+                Symbol::Terminal(Terminal::Token(Token::Separator(
+                    Separator::CloseParenthesis,
+                ))),
+                Symbol::Terminal(Terminal::Token(Token::Separator(Separator::CloseBrackets))),
+                Symbol::NonTerminal(NonTerminal::FuncAttribute),
+            ],
+        },
+        ParsingRule {
+            non_terminal: NonTerminal::FuncAttribute,
+            token: Terminal::Any,
+            production: &[Symbol::Terminal(Terminal::Epsilon)],
+        },
+        /*
+
+            <AttributeLint> :: id <AttributeLintList>
+
+        */
+        ParsingRule {
+            non_terminal: NonTerminal::AttributeLint,
+            token: id,
+            production: &[
+                Symbol::Terminal(id),
+                Symbol::NonTerminal(NonTerminal::AttributeLintList),
+            ],
+        },
+        /*
+
+            <AttributeLintList> :: , id <AttributeLintList> | e
+
+        */
+        ParsingRule {
+            non_terminal: NonTerminal::AttributeLintList,
+            token: Terminal::Token(Token::Separator(Separator::Comma)),
+            production: &[
+                Symbol::Terminal(Terminal::Token(Token::Separator(Separator::Comma))),
+                Symbol::Terminal(id),
+                Symbol::NonTerminal(NonTerminal::AttributeLintList),
+            ],
+        },
+        ParsingRule {
+            non_terminal: NonTerminal::AttributeLintList,
+            token: Terminal::Any,
+            production: &[Symbol::Terminal(Terminal::Epsilon)],
+        },
+        /*
+
+            <FuncArgument> :: <DataType> <TypeArgs> id <FuncArgument>
+                            | , <DataType> <TypeArgs> id <FuncArgument>
+                            | e
 
         */
         ParsingRule {
@@ -74,6 +162,7 @@ impl Parser for Function {
             token: typed,
             production: &[
                 Symbol::Terminal(typed),
+                Symbol::NonTerminal(NonTerminal::TypeArgs),
                 Symbol::Terminal(id),
                 Symbol::NonTerminal(NonTerminal::FuncArgument),
             ],
@@ -84,6 +173,7 @@ impl Parser for Function {
             production: &[
                 Symbol::Terminal(Terminal::Token(Token::Separator(Separator::Comma))),
                 Symbol::Terminal(typed),
+                Symbol::NonTerminal(NonTerminal::TypeArgs),
                 Symbol::Terminal(id),
                 Symbol::NonTerminal(NonTerminal::FuncArgument),
             ],
@@ -129,13 +219,16 @@ impl Parser for Function {
         /*
 
             <Statement> ::
-                return <Expr> ;
+                return <StmntReturnValue> ;
+                    | break ;
+                    | continue ;
                     | if ( <Expr> ) { <StmntList> } <StmntElse>
                     | match ( <Expr> ) { <StmntCase> }
                     | for ( <StmntDecl> ; <Expr> ; <StmntAssign> ) { <StmntList> }
                     | while ( <Expr> ) { <StmntList> }
                     | <StmntDecl> ;
                     | <StmntAssign> ;
+                    | { <StmntList> }
 
         */
         ParsingRule {
@@ -143,7 +236,23 @@ impl Parser for Function {
             token: Terminal::Token(Token::ReservedWord(ReservedWord::Return)),
             production: &[
                 Symbol::Terminal(Terminal::Token(Token::ReservedWord(ReservedWord::Return))),
-                Symbol::NonTerminal(NonTerminal::Expr),
+                Symbol::NonTerminal(NonTerminal::StmntReturnValue),
+                Symbol::Terminal(Terminal::Token(Token::Separator(Separator::Terminator))),
+            ],
+        },
+        ParsingRule {
+            non_terminal: NonTerminal::Statement,
+            token: Terminal::Token(Token::ReservedWord(ReservedWord::Break)),
+            production: &[
+                Symbol::Terminal(Terminal::Token(Token::ReservedWord(ReservedWord::Break))),
+                Symbol::Terminal(Terminal::Token(Token::Separator(Separator::Terminator))),
+            ],
+        },
+        ParsingRule {
+            non_terminal: NonTerminal::Statement,
+            token: Terminal::Token(Token::ReservedWord(ReservedWord::Continue)),
+            production: &[
+                Symbol::Terminal(Terminal::Token(Token::ReservedWord(ReservedWord::Continue))),
                 Symbol::Terminal(Terminal::Token(Token::Separator(Separator::Terminator))),
             ],
         },
@@ -260,9 +369,37 @@ impl Parser for Function {
                 Symbol::Terminal(Terminal::Token(Token::Separator(Separator::Terminator))),
             ],
         },
+        ParsingRule {
+            non_terminal: NonTerminal::Statement,
+            token: Terminal::Token(Token::Separator(Separator::OpenCurlyBraces)),
+            production: &[
+                Symbol::Terminal(Terminal::Token(Token::Separator(
+                    Separator::OpenCurlyBraces,
+                ))),
+                Symbol::NonTerminal(NonTerminal::StmntList),
+                Symbol::Terminal(Terminal::Token(Token::Separator(
+                    Separator::CloseCurlyBraces,
+                ))),
+            ],
+        },
         /*
 
-           <StmntCase> :: case <Literal> { <StmntList> } <StmntCase>
+            <StmntReturnValue> :: <Expr> | e
+
+        */
+        ParsingRule {
+            non_terminal: NonTerminal::StmntReturnValue,
+            token: Terminal::Token(Token::Separator(Separator::Terminator)),
+            production: &[Symbol::Terminal(Terminal::Epsilon)],
+        },
+        ParsingRule {
+            non_terminal: NonTerminal::StmntReturnValue,
+            token: Terminal::Any,
+            production: &[Symbol::NonTerminal(NonTerminal::Expr)],
+        },
+        /*
+
+           <StmntCase> :: case <CasePattern> <CasePatternList> { <StmntList> } <StmntCase>
                         | default { <StmntList> }
                         | e
 
@@ -272,7 +409,8 @@ impl Parser for Function {
             token: Terminal::Token(Token::ReservedWord(ReservedWord::Case)),
             production: &[
                 Symbol::Terminal(Terminal::Token(Token::ReservedWord(ReservedWord::Case))),
-                Symbol::Terminal(literal),
+                Symbol::NonTerminal(NonTerminal::CasePattern),
+                Symbol::NonTerminal(NonTerminal::CasePatternList),
                 Symbol::Terminal(Terminal::Token(Token::Separator(
                     Separator::OpenCurlyBraces,
                 ))),
@@ -304,7 +442,134 @@ impl Parser for Function {
         },
         /*
 
-            <Expr> :: <ExprOperand> <ExprOperation>
+            <CasePattern> :: literal <CaseRange> | id <CaseVariant> <CaseBindings>
+
+        */
+        ParsingRule {
+            non_terminal: NonTerminal::CasePattern,
+            token: literal,
+            production: &[
+                Symbol::Terminal(literal),
+                Symbol::NonTerminal(NonTerminal::CaseRange),
+            ],
+        },
+        ParsingRule {
+            non_terminal: NonTerminal::CasePattern,
+            token: id,
+            production: &[
+                Symbol::Terminal(id),
+                Symbol::NonTerminal(NonTerminal::CaseVariant),
+                Symbol::NonTerminal(NonTerminal::CaseBindings),
+            ],
+        },
+        /*
+
+            <CasePatternList> :: , <CasePattern> <CasePatternList> | e
+
+        */
+        ParsingRule {
+            non_terminal: NonTerminal::CasePatternList,
+            token: Terminal::Token(Token::Separator(Separator::Comma)),
+            production: &[
+                Symbol::Terminal(Terminal::Token(Token::Separator(Separator::Comma))),
+                Symbol::NonTerminal(NonTerminal::CasePattern),
+                Symbol::NonTerminal(NonTerminal::CasePatternList),
+            ],
+        },
+        ParsingRule {
+            non_terminal: NonTerminal::CasePatternList,
+            token: Terminal::Token(Token::Separator(Separator::OpenCurlyBraces)),
+            production: &[Symbol::Terminal(Terminal::Epsilon)],
+        },
+        /*
+
+            <CaseRange> :: ..= literal | e
+
+        */
+        ParsingRule {
+            non_terminal: NonTerminal::CaseRange,
+            token: Terminal::Token(Token::Separator(Separator::InclusiveRange)),
+            production: &[
+                Symbol::Terminal(Terminal::Token(Token::Separator(Separator::InclusiveRange))),
+                Symbol::Terminal(literal),
+            ],
+        },
+        ParsingRule {
+            non_terminal: NonTerminal::CaseRange,
+            token: Terminal::Any,
+            production: &[Symbol::Terminal(Terminal::Epsilon)],
+        },
+        /*
+
+            <CaseVariant> :: :: id | e
+
+        */
+        ParsingRule {
+            non_terminal: NonTerminal::CaseVariant,
+            token: Terminal::Token(Token::Separator(Separator::DoubleColon)),
+            production: &[
+                Symbol::Terminal(Terminal::Token(Token::Separator(Separator::DoubleColon))),
+                Symbol::Terminal(id),
+            ],
+        },
+        ParsingRule {
+            non_terminal: NonTerminal::CaseVariant,
+            token: Terminal::Any,
+            production: &[Symbol::Terminal(Terminal::Epsilon)],
+        },
+        /*
+
+            <CaseBindings> :: ( <CaseBindingList> ) | e
+
+        */
+        ParsingRule {
+            non_terminal: NonTerminal::CaseBindings,
+            token: Terminal::Token(Token::Separator(Separator::OpenParenthesis)),
+            production: &[
+                Symbol::Terminal(Terminal::Token(Token::Separator(
+                    Separator::OpenParenthesis,
+                ))),
+                Symbol::NonTerminal(NonTerminal::CaseBindingList),
+                Symbol::Terminal(Terminal::Token(Token::Separator(
+                    Separator::CloseParenthesis,
+                ))),
+            ],
+        },
+        ParsingRule {
+            non_terminal: NonTerminal::CaseBindings,
+            token: Terminal::Any,
+            production: &[Symbol::Terminal(Terminal::Epsilon)],
+        },
+        /*
+
+            <CaseBindingList> :: id <CaseBindingList> | , id <CaseBindingList> | e
+
+        */
+        ParsingRule {
+            non_terminal: NonTerminal::CaseBindingList,
+            token: id,
+            production: &[
+                Symbol::Terminal(id),
+                Symbol::NonTerminal(NonTerminal::CaseBindingList),
+            ],
+        },
+        ParsingRule {
+            non_terminal: NonTerminal::CaseBindingList,
+            token: Terminal::Token(Token::Separator(Separator::Comma)),
+            production: &[
+                Symbol::Terminal(Terminal::Token(Token::Separator(Separator::Comma))),
+                Symbol::Terminal(id),
+                Symbol::NonTerminal(NonTerminal::CaseBindingList),
+            ],
+        },
+        ParsingRule {
+            non_terminal: NonTerminal::CaseBindingList,
+            token: Terminal::Token(Token::Separator(Separator::CloseParenthesis)),
+            production: &[Symbol::Terminal(Terminal::Epsilon)],
+        },
+        /*
+
+            <Expr> :: <ExprOperand> <ExprCast> <ExprOperation>
 
         */
         ParsingRule {
@@ -312,12 +577,13 @@ impl Parser for Function {
             token: Terminal::Any,
             production: &[
                 Symbol::NonTerminal(NonTerminal::ExprOperand),
+                Symbol::NonTerminal(NonTerminal::ExprCast),
                 Symbol::NonTerminal(NonTerminal::ExprOperation),
             ],
         },
         /*
 
-            <ExprOperand> :: id <ExprCall> | literal
+            <ExprOperand> :: id <ExprCall> | literal | ( <Expr> ) | ! <ExprOperand> | - <ExprOperand>
 
         */
         ParsingRule {
@@ -333,6 +599,54 @@ impl Parser for Function {
             token: literal,
             production: &[Symbol::Terminal(literal)],
         },
+        ParsingRule {
+            non_terminal: NonTerminal::ExprOperand,
+            token: Terminal::Token(Token::Separator(Separator::OpenParenthesis)),
+            production: &[
+                Symbol::Terminal(Terminal::Token(Token::Separator(
+                    Separator::OpenParenthesis,
+                ))),
+                Symbol::NonTerminal(NonTerminal::Expr),
+                Symbol::Terminal(Terminal::Token(Token::Separator(
+                    Separator::CloseParenthesis,
+                ))),
+            ],
+        },
+        ParsingRule {
+            non_terminal: NonTerminal::ExprOperand,
+            token: Terminal::Token(Token::Operator(Operator::Negation)),
+            production: &[
+                Symbol::Terminal(Terminal::Token(Token::Operator(Operator::Negation))),
+                Symbol::NonTerminal(NonTerminal::ExprOperand),
+            ],
+        },
+        ParsingRule {
+            non_terminal: NonTerminal::ExprOperand,
+            token: Terminal::Token(Token::Operator(Operator::Subtraction)),
+            production: &[
+                Symbol::Terminal(Terminal::Token(Token::Operator(Operator::Subtraction))),
+                Symbol::NonTerminal(NonTerminal::ExprOperand),
+            ],
+        },
+        /*
+
+            <ExprCast> :: as <DataType> <ExprCast> | e
+
+        */
+        ParsingRule {
+            non_terminal: NonTerminal::ExprCast,
+            token: Terminal::Token(Token::ReservedWord(ReservedWord::As)),
+            production: &[
+                Symbol::Terminal(Terminal::Token(Token::ReservedWord(ReservedWord::As))),
+                Symbol::Terminal(typed),
+                Symbol::NonTerminal(NonTerminal::ExprCast),
+            ],
+        },
+        ParsingRule {
+            non_terminal: NonTerminal::ExprCast,
+            token: Terminal::Any,
+            production: &[Symbol::Terminal(Terminal::Epsilon)],
+        },
         /*
 
             <ExprCall> ::
@@ -455,6 +769,30 @@ impl Parser for Function {
                 Symbol::NonTerminal(NonTerminal::ExprFuncCallArgs),
             ],
         },
+        ParsingRule {
+            non_terminal: NonTerminal::ExprFuncCallArgs,
+            token: Terminal::Token(Token::Separator(Separator::OpenParenthesis)),
+            production: &[
+                Symbol::NonTerminal(NonTerminal::Expr),
+                Symbol::NonTerminal(NonTerminal::ExprFuncCallArgs),
+            ],
+        },
+        ParsingRule {
+            non_terminal: NonTerminal::ExprFuncCallArgs,
+            token: Terminal::Token(Token::Operator(Operator::Negation)),
+            production: &[
+                Symbol::NonTerminal(NonTerminal::Expr),
+                Symbol::NonTerminal(NonTerminal::ExprFuncCallArgs),
+            ],
+        },
+        ParsingRule {
+            non_terminal: NonTerminal::ExprFuncCallArgs,
+            token: Terminal::Token(Token::Operator(Operator::Subtraction)),
+            production: &[
+                Symbol::NonTerminal(NonTerminal::Expr),
+                Symbol::NonTerminal(NonTerminal::ExprFuncCallArgs),
+            ],
+        },
         ParsingRule {
             non_terminal: NonTerminal::ExprFuncCallArgs,
             token: Terminal::Token(Token::Separator(Separator::Comma)),
@@ -558,8 +896,8 @@ impl Parser for Function {
         },
         /*
 
-            <StmntDecl> :: let <DataType> id = <Expr>
-                        | const <DataType> id = <Expr>
+            <StmntDecl> :: let <StmntDeclType>
+                        | const <StmntDeclType>
 
         */
         ParsingRule {
@@ -567,10 +905,7 @@ impl Parser for Function {
             token: Terminal::Token(Token::ReservedWord(ReservedWord::Let)),
             production: &[
                 Symbol::Terminal(Terminal::Token(Token::ReservedWord(ReservedWord::Let))),
-                Symbol::Terminal(typed),
-                Symbol::Terminal(id),
-                Symbol::Terminal(Terminal::Token(Token::Operator(Operator::Assignment))),
-                Symbol::NonTerminal(NonTerminal::Expr),
+                Symbol::NonTerminal(NonTerminal::StmntDeclType),
             ],
         },
         ParsingRule {
@@ -578,15 +913,90 @@ impl Parser for Function {
             token: Terminal::Token(Token::ReservedWord(ReservedWord::Constant)),
             production: &[
                 Symbol::Terminal(Terminal::Token(Token::ReservedWord(ReservedWord::Constant))),
+                Symbol::NonTerminal(NonTerminal::StmntDeclType),
+            ],
+        },
+        /*
+
+            <StmntDeclType> :: id <StmntDeclName>
+                            | <DataType> id <StmntDeclValue>
+
+            A leading id is either a structure or enumeration type or, when the type is left
+            to be inferred, the variable itself; <StmntDeclName> tells them apart.
+
+        */
+        ParsingRule {
+            non_terminal: NonTerminal::StmntDeclType,
+            token: id,
+            production: &[
+                Symbol::Terminal(id),
+                Symbol::NonTerminal(NonTerminal::StmntDeclName),
+            ],
+        },
+        ParsingRule {
+            non_terminal: NonTerminal::StmntDeclType,
+            token: typed,
+            production: &[
                 Symbol::Terminal(typed),
                 Symbol::Terminal(id),
+                Symbol::NonTerminal(NonTerminal::StmntDeclValue),
+            ],
+        },
+        /*
+
+            <StmntDeclName> :: id <StmntDeclValue>
+                            | <TypeArgs> id <StmntDeclValue>
+                            | = <Expr>
+
+            Type arguments after the leading id make it a generic structure type.
+
+        */
+        ParsingRule {
+            non_terminal: NonTerminal::StmntDeclName,
+            token: id,
+            production: &[
+                Symbol::Terminal(id),
+                Symbol::NonTerminal(NonTerminal::StmntDeclValue),
+            ],
+        },
+        ParsingRule {
+            non_terminal: NonTerminal::StmntDeclName,
+            token: Terminal::Token(Token::Operator(Operator::LessThan)),
+            production: &[
+                Symbol::NonTerminal(NonTerminal::TypeArgs),
+                Symbol::Terminal(id),
+                Symbol::NonTerminal(NonTerminal::StmntDeclValue),
+            ],
+        },
+        ParsingRule {
+            non_terminal: NonTerminal::StmntDeclName,
+            token: Terminal::Token(Token::Operator(Operator::Assignment)),
+            production: &[
+                Symbol::Terminal(Terminal::Token(Token::Operator(Operator::Assignment))),
+                Symbol::NonTerminal(NonTerminal::Expr),
+            ],
+        },
+        /*
+
+            <StmntDeclValue> :: = <Expr> | e
+
+        */
+        ParsingRule {
+            non_terminal: NonTerminal::StmntDeclValue,
+            token: Terminal::Token(Token::Operator(Operator::Assignment)),
+            production: &[
                 Symbol::Terminal(Terminal::Token(Token::Operator(Operator::Assignment))),
                 Symbol::NonTerminal(NonTerminal::Expr),
             ],
         },
+        ParsingRule {
+            non_terminal: NonTerminal::StmntDeclValue,
+            token: Terminal::Any,
+            production: &[Symbol::Terminal(Terminal::Epsilon)],
+        },
         /*
 
-            <StmntAssign> :: id = <ExprCall> <ReassignOp> <Expr>
+            <StmntAssign> :: id <ExprCall> <StmntAssignValue>
 
         */
         ParsingRule {
@@ -595,9 +1005,26 @@ impl Parser for Function {
             production: &[
                 Symbol::Terminal(id),
                 Symbol::NonTerminal(NonTerminal::ExprCall),
-                Symbol::Terminal(Terminal::ReassignOp),
+                Symbol::NonTerminal(NonTerminal::StmntAssignValue),
+            ],
+        },
+        /*
+
+            <StmntAssignValue> :: <ReassignOp> <Expr> | e
+
+        */
+        ParsingRule {
+            non_terminal: NonTerminal::StmntAssignValue,
+            token: reassign_op,
+            production: &[
+                Symbol::Terminal(reassign_op),
                 Symbol::NonTerminal(NonTerminal::Expr),
             ],
         },
+        ParsingRule {
+            non_terminal: NonTerminal::StmntAssignValue,
+            token: Terminal::Any,
+            production: &[Symbol::Terminal(Terminal::Epsilon)],
+        },
     ];
 }
@@ -0,0 +1,82 @@
+use crate::front::lexer::reserved::{Operator, Separator};
+use crate::front::lexer::tokens::Token;
+use crate::front::parser::grammar::{id, typed};
+use crate::front::parser::grammar::{NonTerminal, Parser, ParsingRule, Symbol, Terminal};
+
+pub struct Generics;
+
+impl Parser for Generics {
+    /// <TypeParams> :: < id <TypeParamList> > | ε
+    /// <TypeParamList> :: , id <TypeParamList> | ε
+    /// <TypeArgs> :: < <TypeArg> <TypeArgList> > | ε
+    /// <TypeArg> :: <DataType> <TypeArgs>
+    /// <TypeArgList> :: , <TypeArg> <TypeArgList> | ε
+    const PARSING_TABLE: &'static [ParsingRule<'_>] = &[
+        ParsingRule {
+            non_terminal: NonTerminal::TypeParams,
+            token: Terminal::Token(Token::Operator(Operator::LessThan)),
+            production: &[
+                Symbol::Terminal(Terminal::Token(Token::Operator(Operator::LessThan))),
+                Symbol::Terminal(id),
+                Symbol::NonTerminal(NonTerminal::TypeParamList),
+                Symbol::Terminal(Terminal::Token(Token::Operator(Operator::GreaterThan))),
+            ],
+        },
+        ParsingRule {
+            non_terminal: NonTerminal::TypeParams,
+            token: Terminal::Any,
+            production: &[Symbol::Terminal(Terminal::Epsilon)],
+        },
+        ParsingRule {
+            non_terminal: NonTerminal::TypeParamList,
+            token: Terminal::Token(Token::Separator(Separator::Comma)),
+            production: &[
+                Symbol::Terminal(Terminal::Token(Token::Separator(Separator::Comma))),
+                Symbol::Terminal(id),
+                Symbol::NonTerminal(NonTerminal::TypeParamList),
+            ],
+        },
+        ParsingRule {
+            non_terminal: NonTerminal::TypeParamList,
+            token: Terminal::Token(Token::Operator(Operator::GreaterThan)),
+            production: &[Symbol::Terminal(Terminal::Epsilon)],
+        },
+        ParsingRule {
+            non_terminal: NonTerminal::TypeArgs,
+            token: Terminal::Token(Token::Operator(Operator::LessThan)),
+            production: &[
+                Symbol::Terminal(Terminal::Token(Token::Operator(Operator::LessThan))),
+                Symbol::NonTerminal(NonTerminal::TypeArg),
+                Symbol::NonTerminal(NonTerminal::TypeArgList),
+                Symbol::Terminal(Terminal::Token(Token::Operator(Operator::GreaterThan))),
+            ],
+        },
+        ParsingRule {
+            non_terminal: NonTerminal::TypeArgs,
+            token: Terminal::Any,
+            production: &[Symbol::Terminal(Terminal::Epsilon)],
+        },
+        ParsingRule {
+            non_terminal: NonTerminal::TypeArg,
+            token: typed,
+            production: &[
+                Symbol::Terminal(typed),
+                Symbol::NonTerminal(NonTerminal::TypeArgs),
+            ],
+        },
+        ParsingRule {
+            non_terminal: NonTerminal::TypeArgList,
+            token: Terminal::Token(Token::Separator(Separator::Comma)),
+            production: &[
+                Symbol::Terminal(Terminal::Token(Token::Separator(Separator::Comma))),
+                Symbol::NonTerminal(NonTerminal::TypeArg),
+                Symbol::NonTerminal(NonTerminal::TypeArgList),
+            ],
+        },
+        ParsingRule {
+            non_terminal: NonTerminal::TypeArgList,
+            token: Terminal::Token(Token::Operator(Operator::GreaterThan)),
+            production: &[Symbol::Terminal(Terminal::Epsilon)],
+        },
+    ];
+}
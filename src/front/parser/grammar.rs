@@ -2,12 +2,14 @@
 
 use crate::front::lexer::reserved::{Operator, ReservedWord, Separator};
 use crate::front::lexer::tokens::{Literal, Token};
+use std::fmt;
 use strum_macros::Display;
 
 pub const id: Terminal = Terminal::Token(Token::Identifier(String::new()));
 pub const literal: Terminal = Terminal::Token(Token::Literal(Literal::Int(0)));
 pub const typed: Terminal = Terminal::DataType(DataType::Int);
 pub const unary_op: Terminal = Terminal::UnaryOperator(Operator::Increment);
+pub const reassign_op: Terminal = Terminal::ReassignOp(Operator::Assignment);
 
 #[macro_export]
 macro_rules! rule {
@@ -20,8 +22,13 @@ macro_rules! rule {
     };
 }
 
+/// Leftmost derivation produced by the parser, alongside the token position at which each
+/// production was expanded.
 #[allow(clippy::upper_case_acronyms)]
-pub struct AST(pub(crate) Vec<(NonTerminal, Vec<Symbol>)>);
+pub struct AST(
+    pub(crate) Vec<(NonTerminal, Vec<Symbol>)>,
+    pub(crate) Vec<usize>,
+);
 
 #[derive(Display)]
 pub enum SyntaxError {
@@ -40,21 +47,46 @@ pub enum NonTerminal {
     Enum,
 
     EnumBody,
+    EnumVariantData,
+    EnumDiscriminant,
+    EnumTupleFields,
+    EnumStructFields,
     StructBody,
 
+    TypeParams,
+    TypeParamList,
+    TypeArgs,
+    TypeArg,
+    TypeArgList,
+
     Func,
+    FuncAttribute,
+    AttributeLint,
+    AttributeLintList,
     FuncArgument,
     FuncBody,
 
     Statement,
     StmntList,
     StmntAssign,
+    StmntAssignValue,
+    StmntReturnValue,
     StmntDecl,
+    StmntDeclType,
+    StmntDeclName,
+    StmntDeclValue,
     StmntElse,
     StmntCase,
+    CasePattern,
+    CasePatternList,
+    CaseRange,
+    CaseVariant,
+    CaseBindings,
+    CaseBindingList,
 
     Expr,
     ExprOperand,
+    ExprCast,
     ExprOperation,
     ExprCall,
     ExprFuncCall,
@@ -87,7 +119,7 @@ pub enum Terminal {
     Token(Token),
     DataType(DataType),
     UnaryOperator(Operator),
-    ReassignOp,
+    ReassignOp(Operator),
     Any,
     Epsilon,
 }
@@ -99,7 +131,7 @@ pub struct ParsingRule<'a> {
     pub production: &'a [Symbol],
 }
 
-#[derive(Clone, Debug, PartialEq, Display)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum DataType {
     Int,
     Long,
@@ -110,6 +142,33 @@ pub enum DataType {
     Char,
     Bool,
     Identifier(String),
+    /// Type parameter of the generic structure or function it is used in, such as `T`.
+    Parameter(String),
+    /// Generic structure given its type arguments, such as `Pair<int>`.
+    Applied(String, Vec<DataType>),
+}
+
+/// Shows the type as it is written in the source.
+impl fmt::Display for DataType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DataType::Int => write!(f, "{}", ReservedWord::Int),
+            DataType::Long => write!(f, "{}", ReservedWord::Long),
+            DataType::Float => write!(f, "{}", ReservedWord::Float),
+            DataType::Double => write!(f, "{}", ReservedWord::Double),
+            DataType::Void => write!(f, "{}", ReservedWord::Void),
+            DataType::Str => write!(f, "{}", ReservedWord::Str),
+            DataType::Char => write!(f, "{}", ReservedWord::Char),
+            DataType::Bool => write!(f, "{}", ReservedWord::Bool),
+            DataType::Identifier(identifier) | DataType::Parameter(identifier) => {
+                write!(f, "{identifier}")
+            }
+            DataType::Applied(identifier, arguments) => {
+                let arguments: Vec<String> = arguments.iter().map(ToString::to_string).collect();
+                write!(f, "{identifier}<{}>", arguments.join(", "))
+            }
+        }
+    }
 }
 
 impl TryFrom<ReservedWord> for DataType {
@@ -162,6 +221,7 @@ impl ParsingRule<'_> {
         matches!(
             token,
             Token::ReservedWord(ReservedWord::Int)
+                | Token::ReservedWord(ReservedWord::Long)
                 | Token::ReservedWord(ReservedWord::Float)
                 | Token::ReservedWord(ReservedWord::Void)
                 | Token::ReservedWord(ReservedWord::Double)
@@ -194,18 +254,18 @@ impl ParsingRule<'_> {
                             | Operator::Multiplication
                             | Operator::Division
                             | Operator::Inequality
-                            | Operator::Negation
                             | Operator::GreaterThan
                             | Operator::LessThan
                             | Operator::Equality
                             | Operator::And
                             | Operator::Or
+                            | Operator::Assignment
                     )
                 } else {
                     false
                 }
             }
-            Terminal::ReassignOp => {
+            Terminal::ReassignOp(_) => {
                 if let Token::Operator(op) = actual {
                     matches!(
                         op,
@@ -224,15 +284,21 @@ impl ParsingRule<'_> {
         tokens: &[Token],
         table: &[ParsingRule],
     ) -> Result<AST, SyntaxError> {
-        let mut stack = vec![Symbol::NonTerminal(NonTerminal::Program)];
+        // Each symbol remembers the production and index it came from, so the value of the token
+        // it matches is stored in that production even after later ones were expanded.
+        let mut stack: Vec<(Symbol, Option<(usize, usize)>)> =
+            vec![(Symbol::NonTerminal(NonTerminal::Program), None)];
         let mut pos = 0;
         let mut raw_productions: Vec<(NonTerminal, Vec<Symbol>)> = Vec::new();
+        let mut positions: Vec<usize> = Vec::new();
 
-        while let Some(top) = stack.pop() {
+        while let Some((top, origin)) = stack.pop() {
             match top {
                 Symbol::Terminal(expected) => {
-                    if let Some(token) = tokens.get(pos) {
-                        update_production_with_token_value(token, &expected, &mut raw_productions);
+                    if let (Some(token), Some((production, index))) = (tokens.get(pos), origin) {
+                        if let Some(value) = terminal_value(token, &expected) {
+                            raw_productions[production].1[index] = Symbol::Terminal(value);
+                        }
                     }
                     if ParsingRule::matches_token(
                         &expected.clone(),
@@ -262,14 +328,17 @@ impl ParsingRule<'_> {
                             None => continue,
                         },
                     ) {
+                        let production = raw_productions.len();
                         rule.production
                             .iter()
+                            .enumerate()
                             .rev()
-                            .filter(|symbol| **symbol != Symbol::Terminal(Terminal::Epsilon))
-                            .for_each(|symbol| {
-                                stack.push(symbol.clone());
+                            .filter(|(_, symbol)| **symbol != Symbol::Terminal(Terminal::Epsilon))
+                            .for_each(|(index, symbol)| {
+                                stack.push((symbol.clone(), Some((production, index))));
                             });
                         raw_productions.push((nt, rule.production.to_vec()));
+                        positions.push(pos);
                     } else {
                         return Err(SyntaxError::NoRule(format!(
                             "No rule for NonTerminal {:?} with token {:?} at position {pos}",
@@ -282,7 +351,7 @@ impl ParsingRule<'_> {
         }
 
         if pos <= tokens.len() {
-            Ok(AST(raw_productions))
+            Ok(AST(raw_productions, positions))
         } else {
             Err(SyntaxError::UnconsumedInput(format!(
                 "Unconsumed input at position {}",
@@ -292,85 +361,26 @@ impl ParsingRule<'_> {
     }
 }
 
-fn update_production_with_token_value(
-    token: &Token,
-    expected: &Terminal,
-    raw_productions: &mut [(NonTerminal, Vec<Symbol>)],
-) {
-    if let Some(last_production) = raw_productions.last_mut() {
-        match token {
-            Token::Identifier(identifier) => {
-                if let Terminal::DataType(_) = expected {
-                    update_symbols_in_production(
-                        &mut last_production.1,
-                        |symbol| matches!(symbol, Symbol::Terminal(Terminal::DataType(_))),
-                        |_| {
-                            Symbol::Terminal(Terminal::DataType(DataType::Identifier(
-                                identifier.clone(),
-                            )))
-                        },
-                    );
-                } else {
-                    update_symbols_in_production(
-                        &mut last_production.1,
-                        |symbol| {
-                            matches!(
-                                symbol,
-                                Symbol::Terminal(Terminal::Token(Token::Identifier(_)))
-                            )
-                        },
-                        |_| {
-                            Symbol::Terminal(Terminal::Token(Token::Identifier(identifier.clone())))
-                        },
-                    );
-                }
-            }
-            Token::Literal(lit) => {
-                update_symbols_in_production(
-                    &mut last_production.1,
-                    |symbol| matches!(symbol, Symbol::Terminal(Terminal::Token(Token::Literal(_)))),
-                    |_| Symbol::Terminal(Terminal::Token(Token::Literal(lit.clone()))),
-                );
-            }
-            Token::ReservedWord(word)
-                if ParsingRule::is_data_type(&Token::ReservedWord(word.clone())) =>
-            {
-                if let Ok(data_type) = DataType::try_from(word.clone()) {
-                    update_symbols_in_production(
-                        &mut last_production.1,
-                        |symbol| matches!(symbol, Symbol::Terminal(Terminal::DataType(_))),
-                        |_| Symbol::Terminal(Terminal::DataType(data_type.clone())),
-                    );
-                }
-            }
-            Token::Operator(operator) => {
-                if let Terminal::UnaryOperator(_) = expected {
-                    update_symbols_in_production(
-                        &mut last_production.1,
-                        |symbol| matches!(symbol, Symbol::Terminal(Terminal::UnaryOperator(_))),
-                        |_| Symbol::Terminal(Terminal::UnaryOperator(operator.to_owned())),
-                    );
-                } else if let Terminal::ReassignOp = expected {
-                    update_symbols_in_production(
-                        &mut last_production.1,
-                        |symbol| matches!(symbol, Symbol::Terminal(Terminal::ReassignOp)),
-                        |_| Symbol::Terminal(Terminal::ReassignOp),
-                    );
-                }
-            }
-            _ => {}
+/// The `expected` terminal standing for any identifier, literal, type or operator, filled in
+/// with the value of the `token` it matched.
+fn terminal_value(token: &Token, expected: &Terminal) -> Option<Terminal> {
+    match (expected, token) {
+        (Terminal::DataType(_), Token::Identifier(identifier)) => {
+            Some(Terminal::DataType(DataType::Identifier(identifier.clone())))
         }
-    }
-}
-
-fn update_symbols_in_production<F, G>(symbols: &mut [Symbol], predicate: F, transformer: G)
-where
-    F: Fn(&Symbol) -> bool,
-    G: Fn(&Symbol) -> Symbol,
-{
-    for symbol in symbols.iter_mut() {
-        if predicate(symbol) {
-            *symbol = transformer(symbol);
+        (Terminal::DataType(_), Token::ReservedWord(word)) => DataType::try_from(word.clone())
+            .ok()
+            .map(Terminal::DataType),
+        (Terminal::Token(Token::Identifier(_)), Token::Identifier(_))
+        | (Terminal::Token(Token::Literal(_)), Token::Literal(_)) => {
+            Some(Terminal::Token(token.clone()))
+        }
+        (Terminal::UnaryOperator(_), Token::Operator(operator)) => {
+            Some(Terminal::UnaryOperator(operator.clone()))
+        }
+        (Terminal::ReassignOp(_), Token::Operator(operator)) => {
+            Some(Terminal::ReassignOp(operator.clone()))
         }
+        _ => None,
     }
 }
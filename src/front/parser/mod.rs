@@ -2,6 +2,8 @@
 
 pub mod enumeration;
 pub mod function;
+pub mod generics;
 pub mod grammar;
 pub mod program;
 pub mod structure;
+pub mod syntax_tree;
@@ -1,17 +1,25 @@
-use crate::front::lexer::reserved::ReservedWord;
+use crate::front::lexer::reserved::{ReservedWord, Separator};
 use crate::front::lexer::tokens::Token;
 use crate::front::parser::grammar::{NonTerminal, Parser, ParsingRule, Symbol, Terminal};
 
 pub struct Program;
 
 impl Parser for Program {
-    /// <Program> :: <Func> | <Enum> | <Struct> | ε
+    /// <Program> :: <Func> | <FuncAttribute> <Func> | <Enum> | <Struct> | <StmntDecl> ; <Program> | ε
     const PARSING_TABLE: &'static [ParsingRule<'_>] = &[
         ParsingRule {
             non_terminal: NonTerminal::Program,
             token: Terminal::Token(Token::ReservedWord(ReservedWord::Function)),
             production: &[Symbol::NonTerminal(NonTerminal::Func)],
         },
+        ParsingRule {
+            non_terminal: NonTerminal::Program,
+            token: Terminal::Token(Token::Separator(Separator::Hash)),
+            production: &[
+                Symbol::NonTerminal(NonTerminal::FuncAttribute),
+                Symbol::NonTerminal(NonTerminal::Func),
+            ],
+        },
         ParsingRule {
             non_terminal: NonTerminal::Program,
             token: Terminal::Token(Token::ReservedWord(ReservedWord::Enum)),
@@ -22,5 +30,23 @@ impl Parser for Program {
             token: Terminal::Token(Token::ReservedWord(ReservedWord::Struct)),
             production: &[Symbol::NonTerminal(NonTerminal::Struct)],
         },
+        ParsingRule {
+            non_terminal: NonTerminal::Program,
+            token: Terminal::Token(Token::ReservedWord(ReservedWord::Let)),
+            production: &[
+                Symbol::NonTerminal(NonTerminal::StmntDecl),
+                Symbol::Terminal(Terminal::Token(Token::Separator(Separator::Terminator))),
+                Symbol::NonTerminal(NonTerminal::Program),
+            ],
+        },
+        ParsingRule {
+            non_terminal: NonTerminal::Program,
+            token: Terminal::Token(Token::ReservedWord(ReservedWord::Constant)),
+            production: &[
+                Symbol::NonTerminal(NonTerminal::StmntDecl),
+                Symbol::Terminal(Terminal::Token(Token::Separator(Separator::Terminator))),
+                Symbol::NonTerminal(NonTerminal::Program),
+            ],
+        },
     ];
 }
@@ -6,7 +6,8 @@ use crate::front::parser::grammar::{NonTerminal, Parser, ParsingRule, Symbol, Te
 pub struct Struct;
 
 impl Parser for Struct {
-    /// <Struct> :: struct <Identifier> { <StructBody> }
+    /// <Struct> :: struct <Identifier> <TypeParams> { <StructBody> }
+    /// <StructBody> :: <DataType> <TypeArgs> id <StructBody> | , <StructBody> | ε
     const PARSING_TABLE: &'static [ParsingRule<'_>] = &[
         ParsingRule {
             non_terminal: NonTerminal::Struct,
@@ -14,6 +15,7 @@ impl Parser for Struct {
             production: &[
                 Symbol::Terminal(Terminal::Token(Token::ReservedWord(ReservedWord::Struct))),
                 Symbol::Terminal(id),
+                Symbol::NonTerminal(NonTerminal::TypeParams),
                 Symbol::Terminal(Terminal::Token(Token::Separator(
                     Separator::OpenCurlyBraces,
                 ))),
@@ -29,6 +31,7 @@ impl Parser for Struct {
             token: typed,
             production: &[
                 Symbol::Terminal(typed),
+                Symbol::NonTerminal(NonTerminal::TypeArgs),
                 Symbol::Terminal(id),
                 Symbol::NonTerminal(NonTerminal::StructBody),
             ],
@@ -0,0 +1,988 @@
+use crate::front::lexer::reserved::{Operator, ReservedWord, Separator};
+use crate::front::lexer::tokens::{Literal, Token};
+use crate::front::parser::grammar::{DataType, NonTerminal, Symbol, Terminal, AST};
+use std::fmt;
+use std::fmt::Display;
+use std::iter::{Peekable, Zip};
+use std::slice::Iter;
+
+pub const WILDCARD: &str = "_";
+
+/// Index of the first token of a production in the filtered token stream.
+pub type Position = usize;
+
+pub type Block = Vec<Stmnt>;
+
+#[derive(Debug)]
+pub struct SyntaxTree {
+    pub items: Vec<Item>,
+}
+
+#[derive(Debug)]
+pub enum Item {
+    Func(FuncDecl),
+    Struct(StructDecl),
+    Enum(EnumDecl),
+    Global(VarDecl),
+}
+
+/// `<DataType> id` pair, shared by function arguments and struct fields.
+#[derive(Debug, Clone)]
+pub struct TypedIdentifier {
+    pub identifier: String,
+    pub data_type: DataType,
+    pub position: Position,
+}
+
+#[derive(Debug, Clone)]
+pub struct FuncDecl {
+    pub identifier: String,
+    /// `<T, ...>` after the name, empty unless the function is generic.
+    pub type_params: Vec<(String, Position)>,
+    pub return_type: DataType,
+    pub arguments: Vec<TypedIdentifier>,
+    pub body: Block,
+    pub attributes: Vec<Attribute>,
+    pub position: Position,
+}
+
+/// `#[name(lint, ...)]` written before a function.
+#[derive(Debug, Clone)]
+pub struct Attribute {
+    pub name: String,
+    pub lints: Vec<(String, Position)>,
+    pub position: Position,
+}
+
+#[derive(Debug)]
+pub struct StructDecl {
+    pub identifier: String,
+    /// `<T, ...>` after the name, empty unless the structure is generic.
+    pub type_params: Vec<(String, Position)>,
+    pub fields: Vec<TypedIdentifier>,
+    pub position: Position,
+}
+
+#[derive(Debug)]
+pub struct EnumDecl {
+    pub identifier: String,
+    pub variants: Vec<VariantDecl>,
+    pub position: Position,
+}
+
+#[derive(Debug)]
+pub struct VariantDecl {
+    pub identifier: String,
+    /// Explicit `= value`, `None` when the variant follows the previous one.
+    pub discriminant: Option<Expr>,
+    pub payload: VariantPayload,
+    pub position: Position,
+}
+
+/// Data carried by an enumeration variant.
+#[derive(Debug, Clone)]
+pub enum VariantPayload {
+    Unit,
+    /// `Variant(<DataType>, ...)`
+    Tuple(Vec<DataType>),
+    /// `Variant { <DataType> id, ... }`
+    Struct(Vec<TypedIdentifier>),
+}
+
+impl VariantPayload {
+    /// Types of the values carried, in declaration order.
+    pub fn data_types(&self) -> Vec<&DataType> {
+        match self {
+            VariantPayload::Unit => Vec::new(),
+            VariantPayload::Tuple(data_types) => data_types.iter().collect(),
+            VariantPayload::Struct(fields) => fields.iter().map(|field| &field.data_type).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct VarDecl {
+    pub identifier: String,
+    /// Declared type, `None` when it is left to be inferred from the value.
+    pub data_type: Option<DataType>,
+    pub mutable: bool,
+    /// Initial value, `None` when the variable is assigned later on.
+    pub value: Option<Expr>,
+    pub position: Position,
+}
+
+#[derive(Debug, Clone)]
+pub struct Assign {
+    pub target: Expr,
+    /// `=`, `+=` or `-=`.
+    pub operator: Operator,
+    pub value: Expr,
+    pub position: Position,
+}
+
+#[derive(Debug, Clone)]
+pub struct Case {
+    pub patterns: Vec<Pattern>,
+    pub body: Block,
+    pub position: Position,
+}
+
+#[derive(Debug, Clone)]
+pub enum Pattern {
+    Literal(Literal, Position),
+    /// `start..=end`
+    Range(Literal, Literal, Position),
+    /// `Enum::Variant`, or a bare `Variant` whose enumeration comes from the scrutinee, with
+    /// the names its payload is bound to when it is followed by `(id, ...)`.
+    Variant {
+        enumeration: Option<String>,
+        variant: String,
+        bindings: Vec<(String, Position)>,
+        position: Position,
+    },
+    /// `_`
+    Wildcard(Position),
+}
+
+impl Pattern {
+    pub fn position(&self) -> Position {
+        match self {
+            Pattern::Literal(_, position)
+            | Pattern::Range(_, _, position)
+            | Pattern::Variant { position, .. }
+            | Pattern::Wildcard(position) => *position,
+        }
+    }
+}
+
+impl Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Pattern::Literal(literal, _) => write!(f, "{literal}"),
+            Pattern::Range(start, end, _) => {
+                write!(f, "{start}{}{end}", Separator::InclusiveRange)
+            }
+            Pattern::Variant {
+                enumeration,
+                variant,
+                bindings,
+                ..
+            } => {
+                if let Some(enumeration) = enumeration {
+                    write!(f, "{enumeration}{}", Separator::DoubleColon)?;
+                }
+                write!(f, "{variant}")?;
+                if !bindings.is_empty() {
+                    let bindings: Vec<_> = bindings.iter().map(|(name, _)| name.as_str()).collect();
+                    write!(f, "({})", bindings.join(", "))?;
+                }
+                Ok(())
+            }
+            Pattern::Wildcard(_) => write!(f, "{WILDCARD}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Stmnt {
+    Decl(VarDecl),
+    Assign(Assign),
+    Return(Option<Expr>, Position),
+    Break(Position),
+    Continue(Position),
+    If {
+        branches: Vec<(Expr, Block)>,
+        otherwise: Option<Block>,
+        position: Position,
+    },
+    Match {
+        scrutinee: Expr,
+        cases: Vec<Case>,
+        default: Option<Block>,
+        position: Position,
+    },
+    For {
+        init: VarDecl,
+        condition: Expr,
+        step: Box<Stmnt>,
+        body: Block,
+        position: Position,
+    },
+    While {
+        condition: Expr,
+        body: Block,
+        position: Position,
+    },
+    Block(Block, Position),
+    Expr(Expr, Position),
+}
+
+impl Stmnt {
+    pub fn position(&self) -> Position {
+        match self {
+            Stmnt::Decl(VarDecl { position, .. })
+            | Stmnt::Assign(Assign { position, .. })
+            | Stmnt::Return(_, position)
+            | Stmnt::Break(position)
+            | Stmnt::Continue(position)
+            | Stmnt::If { position, .. }
+            | Stmnt::Match { position, .. }
+            | Stmnt::For { position, .. }
+            | Stmnt::While { position, .. }
+            | Stmnt::Block(_, position)
+            | Stmnt::Expr(_, position) => *position,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Expr {
+    Literal(Literal, Position),
+    Identifier(String, Position),
+    Call {
+        callee: Box<Expr>,
+        args: Vec<Expr>,
+        position: Position,
+    },
+    Field {
+        base: Box<Expr>,
+        field: String,
+        position: Position,
+    },
+    Index {
+        base: Box<Expr>,
+        index: Box<Expr>,
+        position: Position,
+    },
+    Unary {
+        operator: Operator,
+        operand: Box<Expr>,
+        position: Position,
+    },
+    Cast {
+        operand: Box<Expr>,
+        data_type: DataType,
+        position: Position,
+    },
+    Binary {
+        operator: Operator,
+        lhs: Box<Expr>,
+        rhs: Box<Expr>,
+        position: Position,
+    },
+}
+
+impl Expr {
+    pub fn position(&self) -> Position {
+        match self {
+            Expr::Literal(_, position)
+            | Expr::Identifier(_, position)
+            | Expr::Call { position, .. }
+            | Expr::Field { position, .. }
+            | Expr::Index { position, .. }
+            | Expr::Unary { position, .. }
+            | Expr::Cast { position, .. }
+            | Expr::Binary { position, .. } => *position,
+        }
+    }
+
+    /// Variable a call, field or array access chain starts from, if any.
+    pub fn root_identifier(&self) -> Option<&str> {
+        match self {
+            Expr::Identifier(identifier, _) => Some(identifier),
+            Expr::Call { callee: base, .. }
+            | Expr::Field { base, .. }
+            | Expr::Index { base, .. } => base.root_identifier(),
+            Expr::Literal(..) | Expr::Unary { .. } | Expr::Cast { .. } | Expr::Binary { .. } => {
+                None
+            }
+        }
+    }
+}
+
+/// Binding power of binary operators, higher binds tighter.
+fn precedence(operator: &Operator) -> u8 {
+    match operator {
+        Operator::Assignment => 0,
+        Operator::Or => 1,
+        Operator::And => 2,
+        Operator::Equality | Operator::Inequality => 3,
+        Operator::GreaterThan
+        | Operator::GreaterThanOrEqual
+        | Operator::LessThan
+        | Operator::LessThanOrEqual => 4,
+        Operator::Sum | Operator::Subtraction => 5,
+        _ => 6,
+    }
+}
+
+type Productions<'a> = Zip<Iter<'a, (NonTerminal, Vec<Symbol>)>, Iter<'a, usize>>;
+
+/// Cursor over the derivation. Productions are stored in pre-order, so each nonterminal of a
+/// production is lowered by consuming its whole subtree before moving on to the next one.
+struct Derivation<'a> {
+    productions: Peekable<Productions<'a>>,
+    /// Type parameters of the generic structure or function being lowered.
+    type_params: Vec<String>,
+}
+
+impl<'a> Derivation<'a> {
+    fn expand(&mut self, non_terminal: NonTerminal) -> Option<(&'a [Symbol], Position)> {
+        match self.productions.peek() {
+            Some(((nt, _), _)) if *nt == non_terminal => self
+                .productions
+                .next()
+                .map(|((_, production), position)| (production.as_slice(), *position)),
+            _ => None,
+        }
+    }
+
+    /// Expands `non_terminal` unless the derivation chose its ε production.
+    fn expand_non_empty(&mut self, non_terminal: NonTerminal) -> Option<(&'a [Symbol], Position)> {
+        self.expand(non_terminal)
+            .filter(|(production, _)| AST::not_epsilon(production))
+    }
+
+    fn program(&mut self) -> SyntaxTree {
+        let mut items = Vec::new();
+        while let Some((production, _)) = self.expand(NonTerminal::Program) {
+            match production.first() {
+                Some(Symbol::NonTerminal(NonTerminal::Func)) => items.push(Item::Func(self.func())),
+                Some(Symbol::NonTerminal(NonTerminal::FuncAttribute)) => {
+                    let attributes = self.attributes();
+                    let mut func = self.func();
+                    func.attributes = attributes;
+                    items.push(Item::Func(func))
+                }
+                Some(Symbol::NonTerminal(NonTerminal::Struct)) => {
+                    items.push(Item::Struct(self.structure()))
+                }
+                Some(Symbol::NonTerminal(NonTerminal::Enum)) => {
+                    items.push(Item::Enum(self.enumeration()))
+                }
+                Some(Symbol::NonTerminal(NonTerminal::StmntDecl)) => {
+                    items.push(Item::Global(self.declaration()))
+                }
+                _ => break,
+            }
+        }
+        SyntaxTree { items }
+    }
+
+    fn func(&mut self) -> FuncDecl {
+        // No id or type in prod returns syntax error, thus, unwrapping makes more sense.
+        let (production, position) = self.expand(NonTerminal::Func).unwrap();
+        let return_type = self.data_type(production);
+        let type_params = self.type_params();
+        // The return type comes before the parameters it may use.
+        let return_type = self.resolve(return_type);
+        let mut arguments = Vec::new();
+        while let Some((argument, argument_position)) =
+            self.expand_non_empty(NonTerminal::FuncArgument)
+        {
+            // Arguments after the first start at their comma.
+            let position = match argument.first() {
+                Some(Symbol::Terminal(Terminal::Token(Token::Separator(Separator::Comma)))) => {
+                    argument_position + 1
+                }
+                _ => argument_position,
+            };
+            arguments.push(TypedIdentifier {
+                identifier: AST::get_identifier_in_production(argument).unwrap(),
+                data_type: self.data_type(argument),
+                position,
+            });
+        }
+        let body = match self.expand_non_empty(NonTerminal::FuncBody) {
+            Some(_) => self.stmnt_list(),
+            None => Vec::new(),
+        };
+        self.type_params.clear();
+        FuncDecl {
+            identifier: AST::get_identifier_in_production(production).unwrap(),
+            type_params,
+            return_type,
+            arguments,
+            body,
+            attributes: Vec::new(),
+            position,
+        }
+    }
+
+    fn attributes(&mut self) -> Vec<Attribute> {
+        let mut attributes = Vec::new();
+        while let Some((production, position)) = self.expand_non_empty(NonTerminal::FuncAttribute) {
+            let (lint, lint_position) = self.expand(NonTerminal::AttributeLint).unwrap();
+            let mut lints = vec![(
+                AST::get_identifier_in_production(lint).unwrap(),
+                lint_position,
+            )];
+            // Each further lint comes right after its comma.
+            while let Some((lint, comma_position)) =
+                self.expand_non_empty(NonTerminal::AttributeLintList)
+            {
+                lints.push((
+                    AST::get_identifier_in_production(lint).unwrap(),
+                    comma_position + 1,
+                ));
+            }
+            attributes.push(Attribute {
+                name: AST::get_identifier_in_production(production).unwrap(),
+                lints,
+                position,
+            });
+        }
+        attributes
+    }
+
+    fn structure(&mut self) -> StructDecl {
+        let (production, position) = self.expand(NonTerminal::Struct).unwrap();
+        let type_params = self.type_params();
+        let mut fields = Vec::new();
+        while let Some((field, field_position)) = self.expand_non_empty(NonTerminal::StructBody) {
+            if get_data_type_in_production(field).is_some() {
+                fields.push(TypedIdentifier {
+                    identifier: AST::get_identifier_in_production(field).unwrap(),
+                    data_type: self.data_type(field),
+                    position: field_position,
+                });
+            }
+        }
+        self.type_params.clear();
+        StructDecl {
+            identifier: AST::get_identifier_in_production(production).unwrap(),
+            type_params,
+            fields,
+            position,
+        }
+    }
+
+    fn enumeration(&mut self) -> EnumDecl {
+        let (production, position) = self.expand(NonTerminal::Enum).unwrap();
+        let mut variants = Vec::new();
+        while let Some((variant, variant_position)) = self.expand_non_empty(NonTerminal::EnumBody) {
+            if let Some(identifier) = AST::get_identifier_in_production(variant) {
+                let (discriminant, payload) = self.variant_data();
+                variants.push(VariantDecl {
+                    identifier,
+                    discriminant,
+                    payload,
+                    position: variant_position,
+                });
+            }
+        }
+        EnumDecl {
+            identifier: AST::get_identifier_in_production(production).unwrap(),
+            variants,
+            position,
+        }
+    }
+
+    fn variant_data(&mut self) -> (Option<Expr>, VariantPayload) {
+        let Some((production, _)) = self.expand_non_empty(NonTerminal::EnumVariantData) else {
+            return (None, VariantPayload::Unit);
+        };
+        match production.first() {
+            Some(Symbol::Terminal(Terminal::Token(Token::Operator(Operator::Assignment)))) => {
+                let (discriminant, position) = self.expand(NonTerminal::EnumDiscriminant).unwrap();
+                let value =
+                    Expr::Literal(get_literal_in_production(discriminant).unwrap(), position);
+                let value = match discriminant.first() {
+                    Some(Symbol::Terminal(Terminal::Token(Token::Operator(operator)))) => {
+                        Expr::Unary {
+                            operator: operator.clone(),
+                            operand: Box::new(value),
+                            position,
+                        }
+                    }
+                    _ => value,
+                };
+                (Some(value), VariantPayload::Unit)
+            }
+            Some(Symbol::Terminal(Terminal::Token(Token::Separator(
+                Separator::OpenParenthesis,
+            )))) => {
+                let mut data_types = Vec::new();
+                while let Some((field, _)) = self.expand_non_empty(NonTerminal::EnumTupleFields) {
+                    if get_data_type_in_production(field).is_some() {
+                        data_types.push(self.data_type(field));
+                    }
+                }
+                (None, VariantPayload::Tuple(data_types))
+            }
+            _ => {
+                let mut fields = Vec::new();
+                while let Some((field, field_position)) =
+                    self.expand_non_empty(NonTerminal::EnumStructFields)
+                {
+                    // Fields after the first start at their comma.
+                    let position = match field.first() {
+                        Some(Symbol::Terminal(Terminal::Token(Token::Separator(
+                            Separator::Comma,
+                        )))) => field_position + 1,
+                        _ => field_position,
+                    };
+                    fields.push(TypedIdentifier {
+                        identifier: AST::get_identifier_in_production(field).unwrap(),
+                        data_type: self.data_type(field),
+                        position,
+                    });
+                }
+                (None, VariantPayload::Struct(fields))
+            }
+        }
+    }
+
+    /// `<T, ...>` declaring the type parameters of a generic item, which are in scope for the
+    /// types written after them.
+    fn type_params(&mut self) -> Vec<(String, Position)> {
+        let mut type_params = Vec::new();
+        let mut next = self.expand_non_empty(NonTerminal::TypeParams);
+        // Each parameter comes right after its `<` or comma.
+        while let Some((production, position)) = next {
+            type_params.push((
+                AST::get_identifier_in_production(production).unwrap(),
+                position + 1,
+            ));
+            next = self.expand_non_empty(NonTerminal::TypeParamList);
+        }
+        self.type_params = type_params
+            .iter()
+            .map(|(identifier, _)| identifier.clone())
+            .collect();
+        type_params
+    }
+
+    /// Type written in `production`, applied to the `<TypeArgs>` that follow it.
+    fn data_type(&mut self, production: &[Symbol]) -> DataType {
+        let data_type = get_data_type_in_production(production).unwrap();
+        self.applied(data_type)
+    }
+
+    fn applied(&mut self, data_type: DataType) -> DataType {
+        let mut arguments = Vec::new();
+        if self.expand_non_empty(NonTerminal::TypeArgs).is_some() {
+            loop {
+                let (argument, _) = self.expand(NonTerminal::TypeArg).unwrap();
+                arguments.push(self.data_type(argument));
+                if self.expand_non_empty(NonTerminal::TypeArgList).is_none() {
+                    break;
+                }
+            }
+        }
+        match data_type {
+            data_type if arguments.is_empty() => self.resolve(data_type),
+            DataType::Identifier(identifier) => DataType::Applied(identifier, arguments),
+            data_type => DataType::Applied(data_type.to_string(), arguments),
+        }
+    }
+
+    /// Turns the names of the type parameters in scope into [`DataType::Parameter`], including
+    /// in type arguments.
+    fn resolve(&self, data_type: DataType) -> DataType {
+        match data_type {
+            DataType::Identifier(identifier) if self.type_params.contains(&identifier) => {
+                DataType::Parameter(identifier)
+            }
+            DataType::Applied(identifier, arguments) => DataType::Applied(
+                identifier,
+                arguments
+                    .into_iter()
+                    .map(|argument| self.resolve(argument))
+                    .collect(),
+            ),
+            data_type => data_type,
+        }
+    }
+
+    fn stmnt_list(&mut self) -> Block {
+        let mut block = Vec::new();
+        while self.expand_non_empty(NonTerminal::StmntList).is_some() {
+            block.push(self.statement());
+        }
+        block
+    }
+
+    fn statement(&mut self) -> Stmnt {
+        let (production, position) = self.expand(NonTerminal::Statement).unwrap();
+        match production.first().unwrap() {
+            Symbol::Terminal(Terminal::Token(Token::ReservedWord(ReservedWord::Return))) => {
+                let value = self
+                    .expand_non_empty(NonTerminal::StmntReturnValue)
+                    .map(|_| self.expr());
+                Stmnt::Return(value, position)
+            }
+            Symbol::Terminal(Terminal::Token(Token::ReservedWord(ReservedWord::Break))) => {
+                Stmnt::Break(position)
+            }
+            Symbol::Terminal(Terminal::Token(Token::ReservedWord(ReservedWord::Continue))) => {
+                Stmnt::Continue(position)
+            }
+            Symbol::Terminal(Terminal::Token(Token::ReservedWord(ReservedWord::If))) => {
+                let mut branches = vec![(self.expr(), self.stmnt_list())];
+                let mut otherwise = None;
+                while let Some((else_production, _)) = self.expand_non_empty(NonTerminal::StmntElse)
+                {
+                    match else_production.first() {
+                        Some(Symbol::Terminal(Terminal::Token(Token::ReservedWord(
+                            ReservedWord::Elif,
+                        )))) => branches.push((self.expr(), self.stmnt_list())),
+                        _ => otherwise = Some(self.stmnt_list()),
+                    }
+                }
+                Stmnt::If {
+                    branches,
+                    otherwise,
+                    position,
+                }
+            }
+            Symbol::Terminal(Terminal::Token(Token::ReservedWord(ReservedWord::Match))) => {
+                let scrutinee = self.expr();
+                let mut cases = Vec::new();
+                let mut default = None;
+                while let Some((case_production, case_position)) =
+                    self.expand_non_empty(NonTerminal::StmntCase)
+                {
+                    match case_production.first() {
+                        Some(Symbol::Terminal(Terminal::Token(Token::ReservedWord(
+                            ReservedWord::Case,
+                        )))) => {
+                            let mut patterns = vec![self.pattern()];
+                            while self
+                                .expand_non_empty(NonTerminal::CasePatternList)
+                                .is_some()
+                            {
+                                patterns.push(self.pattern());
+                            }
+                            cases.push(Case {
+                                patterns,
+                                body: self.stmnt_list(),
+                                position: case_position,
+                            })
+                        }
+                        _ => default = Some(self.stmnt_list()),
+                    }
+                }
+                Stmnt::Match {
+                    scrutinee,
+                    cases,
+                    default,
+                    position,
+                }
+            }
+            Symbol::Terminal(Terminal::Token(Token::ReservedWord(ReservedWord::For))) => {
+                Stmnt::For {
+                    init: self.declaration(),
+                    condition: self.expr(),
+                    step: Box::new(self.assignment()),
+                    body: self.stmnt_list(),
+                    position,
+                }
+            }
+            Symbol::Terminal(Terminal::Token(Token::ReservedWord(ReservedWord::While))) => {
+                Stmnt::While {
+                    condition: self.expr(),
+                    body: self.stmnt_list(),
+                    position,
+                }
+            }
+            Symbol::Terminal(Terminal::Token(Token::Separator(Separator::OpenCurlyBraces))) => {
+                Stmnt::Block(self.stmnt_list(), position)
+            }
+            Symbol::NonTerminal(NonTerminal::StmntDecl) => Stmnt::Decl(self.declaration()),
+            Symbol::NonTerminal(NonTerminal::StmntAssign) => self.assignment(),
+            symbol => panic!("Unexpected symbol in statement: {:?}", symbol),
+        }
+    }
+
+    fn pattern(&mut self) -> Pattern {
+        let (production, position) = self.expand(NonTerminal::CasePattern).unwrap();
+        match production.first().unwrap() {
+            Symbol::Terminal(Terminal::Token(Token::Literal(start))) => {
+                match self.expand_non_empty(NonTerminal::CaseRange) {
+                    Some((range, _)) => Pattern::Range(
+                        start.clone(),
+                        get_literal_in_production(range).unwrap(),
+                        position,
+                    ),
+                    None => Pattern::Literal(start.clone(), position),
+                }
+            }
+            Symbol::Terminal(Terminal::Token(Token::Identifier(identifier))) => {
+                let qualified = self
+                    .expand_non_empty(NonTerminal::CaseVariant)
+                    .map(|(variant, _)| AST::get_identifier_in_production(variant).unwrap());
+                let bindings = self.bindings();
+                match qualified {
+                    Some(variant) => Pattern::Variant {
+                        enumeration: Some(identifier.clone()),
+                        variant,
+                        bindings,
+                        position,
+                    },
+                    None if identifier == WILDCARD && bindings.is_empty() => {
+                        Pattern::Wildcard(position)
+                    }
+                    None => Pattern::Variant {
+                        enumeration: None,
+                        variant: identifier.clone(),
+                        bindings,
+                        position,
+                    },
+                }
+            }
+            symbol => panic!("Unexpected symbol in pattern: {:?}", symbol),
+        }
+    }
+
+    fn bindings(&mut self) -> Vec<(String, Position)> {
+        let mut bindings = Vec::new();
+        if self.expand_non_empty(NonTerminal::CaseBindings).is_none() {
+            return bindings;
+        }
+        while let Some((binding, position)) = self.expand_non_empty(NonTerminal::CaseBindingList) {
+            // Each further binding comes right after its comma.
+            let position = match binding.first() {
+                Some(Symbol::Terminal(Terminal::Token(Token::Separator(Separator::Comma)))) => {
+                    position + 1
+                }
+                _ => position,
+            };
+            bindings.push((
+                AST::get_identifier_in_production(binding).unwrap(),
+                position,
+            ));
+        }
+        bindings
+    }
+
+    fn declaration(&mut self) -> VarDecl {
+        let (production, position) = self.expand(NonTerminal::StmntDecl).unwrap();
+        let mutable = matches!(
+            production.first(),
+            Some(Symbol::Terminal(Terminal::Token(Token::ReservedWord(
+                ReservedWord::Let
+            ))))
+        );
+        let (production, _) = self.expand(NonTerminal::StmntDeclType).unwrap();
+        let (identifier, data_type, value) = match get_data_type_in_production(production) {
+            Some(data_type) => (
+                AST::get_identifier_in_production(production).unwrap(),
+                Some(data_type),
+                self.declaration_value(),
+            ),
+            None => {
+                let first = AST::get_identifier_in_production(production).unwrap();
+                let (name_production, _) = self.expand(NonTerminal::StmntDeclName).unwrap();
+                match AST::get_identifier_in_production(name_production) {
+                    Some(identifier) => (
+                        identifier,
+                        Some(self.applied(DataType::Identifier(first))),
+                        self.declaration_value(),
+                    ),
+                    None => (first, None, Some(self.expr())),
+                }
+            }
+        };
+        VarDecl {
+            identifier,
+            data_type,
+            mutable,
+            value,
+            position,
+        }
+    }
+
+    fn declaration_value(&mut self) -> Option<Expr> {
+        self.expand_non_empty(NonTerminal::StmntDeclValue)
+            .map(|_| self.expr())
+    }
+
+    /// `<StmntAssign>` without a value is an expression evaluated for its side effects.
+    fn assignment(&mut self) -> Stmnt {
+        let (production, position) = self.expand(NonTerminal::StmntAssign).unwrap();
+        let base = Expr::Identifier(
+            AST::get_identifier_in_production(production).unwrap(),
+            position,
+        );
+        let target = self.expr_call(base);
+        match self.expand_non_empty(NonTerminal::StmntAssignValue) {
+            Some((value_production, _)) => Stmnt::Assign(Assign {
+                target,
+                operator: match value_production.first() {
+                    Some(Symbol::Terminal(Terminal::ReassignOp(operator))) => operator.clone(),
+                    symbol => panic!("Unexpected symbol in assignment: {:?}", symbol),
+                },
+                value: self.expr(),
+                position,
+            }),
+            None => Stmnt::Expr(target, position),
+        }
+    }
+
+    fn expr(&mut self) -> Expr {
+        let mut operands = Vec::new();
+        let mut operators = Vec::new();
+        self.expr_sequence(&mut operands, &mut operators);
+        fold_binary(operands, operators)
+    }
+
+    /// Flattens `<ExprOperand> <ExprOperation>` chains into operands and the operators between
+    /// them, since the grammar itself carries no precedence.
+    fn expr_sequence(
+        &mut self,
+        operands: &mut Vec<Expr>,
+        operators: &mut Vec<(Operator, Position)>,
+    ) {
+        self.expand(NonTerminal::Expr).unwrap();
+        let operand = self.operand();
+        operands.push(self.cast(operand));
+        while let Some((production, position)) = self.expand_non_empty(NonTerminal::ExprOperation) {
+            if let Some(Symbol::Terminal(Terminal::UnaryOperator(operator))) = production.first() {
+                operators.push((operator.clone(), position));
+            }
+            self.expr_sequence(operands, operators);
+        }
+    }
+
+    fn operand(&mut self) -> Expr {
+        let (production, position) = self.expand(NonTerminal::ExprOperand).unwrap();
+        match production.first().unwrap() {
+            Symbol::Terminal(Terminal::Token(Token::Identifier(identifier))) => {
+                self.expr_call(Expr::Identifier(identifier.clone(), position))
+            }
+            Symbol::Terminal(Terminal::Token(Token::Literal(literal))) => {
+                Expr::Literal(literal.clone(), position)
+            }
+            Symbol::Terminal(Terminal::Token(Token::Separator(Separator::OpenParenthesis))) => {
+                self.expr()
+            }
+            Symbol::Terminal(Terminal::Token(Token::Operator(operator))) => Expr::Unary {
+                operator: operator.clone(),
+                operand: Box::new(self.operand()),
+                position,
+            },
+            symbol => panic!("Unexpected symbol in operand: {:?}", symbol),
+        }
+    }
+
+    /// Wraps `operand` in every `as <DataType>` that follows it, innermost first.
+    fn cast(&mut self, mut operand: Expr) -> Expr {
+        while let Some((production, position)) = self.expand_non_empty(NonTerminal::ExprCast) {
+            operand = Expr::Cast {
+                operand: Box::new(operand),
+                data_type: self.resolve(get_data_type_in_production(production).unwrap()),
+                position,
+            };
+        }
+        operand
+    }
+
+    fn expr_call(&mut self, mut base: Expr) -> Expr {
+        while let Some((production, position)) = self.expand_non_empty(NonTerminal::ExprCall) {
+            base = match production.first().unwrap() {
+                Symbol::NonTerminal(NonTerminal::ExprFuncCall) => {
+                    let mut args = Vec::new();
+                    if self.expand_non_empty(NonTerminal::ExprFuncCall).is_some() {
+                        while self
+                            .expand_non_empty(NonTerminal::ExprFuncCallArgs)
+                            .is_some()
+                        {
+                            args.push(self.expr());
+                        }
+                    }
+                    Expr::Call {
+                        callee: Box::new(base),
+                        args,
+                        position,
+                    }
+                }
+                Symbol::NonTerminal(NonTerminal::ExprArrayAccess) => {
+                    match self.expand_non_empty(NonTerminal::ExprArrayAccess) {
+                        Some(_) => Expr::Index {
+                            base: Box::new(base),
+                            index: Box::new(self.expr()),
+                            position,
+                        },
+                        None => base,
+                    }
+                }
+                Symbol::NonTerminal(NonTerminal::ExprFieldAccess) => {
+                    self.expand(NonTerminal::ExprFieldAccess);
+                    base
+                }
+                Symbol::Terminal(Terminal::Token(Token::Identifier(field))) => Expr::Field {
+                    base: Box::new(base),
+                    field: field.clone(),
+                    position,
+                },
+                symbol => panic!("Unexpected symbol in call: {:?}", symbol),
+            }
+        }
+        base
+    }
+}
+
+/// Rebuilds binary operations from the flattened operand list, applying [`precedence`] with
+/// left associativity.
+fn fold_binary(operands: Vec<Expr>, operators: Vec<(Operator, Position)>) -> Expr {
+    let mut output: Vec<Expr> = Vec::new();
+    let mut pending: Vec<(Operator, Position)> = Vec::new();
+    let reduce = |output: &mut Vec<Expr>, (operator, position): (Operator, Position)| {
+        let rhs = output.pop().unwrap();
+        let lhs = output.pop().unwrap();
+        output.push(Expr::Binary {
+            operator,
+            lhs: Box::new(lhs),
+            rhs: Box::new(rhs),
+            position,
+        });
+    };
+    let mut operands = operands.into_iter();
+    output.push(operands.next().unwrap());
+    for (operator, operand) in operators.into_iter().zip(operands) {
+        while pending
+            .last()
+            .is_some_and(|(top, _)| precedence(top) >= precedence(&operator.0))
+        {
+            reduce(&mut output, pending.pop().unwrap());
+        }
+        pending.push(operator);
+        output.push(operand);
+    }
+    while let Some(operator) = pending.pop() {
+        reduce(&mut output, operator);
+    }
+    output.pop().unwrap()
+}
+
+fn get_data_type_in_production(production: &[Symbol]) -> Option<DataType> {
+    production.iter().find_map(|symbol| {
+        if let Symbol::Terminal(Terminal::DataType(dt)) = symbol {
+            Some(dt.clone())
+        } else {
+            None
+        }
+    })
+}
+
+fn get_literal_in_production(production: &[Symbol]) -> Option<Literal> {
+    production.iter().find_map(|symbol| {
+        if let Symbol::Terminal(Terminal::Token(Token::Literal(literal))) = symbol {
+            Some(literal.clone())
+        } else {
+            None
+        }
+    })
+}
+
+impl AST {
+    /// Lowers the derivation into a tree of declarations, statements and expressions.
+    pub fn syntax_tree(&self) -> SyntaxTree {
+        Derivation {
+            productions: self.0.iter().zip(self.1.iter()).peekable(),
+            type_params: Vec::new(),
+        }
+        .program()
+    }
+}
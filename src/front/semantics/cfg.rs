@@ -0,0 +1,254 @@
+use crate::front::parser::syntax_tree::{Block, Position, Stmnt};
+use crate::front::semantics::fold::constant_condition;
+use crate::front::semantics::functions::match_is_total;
+
+/// Index of a block in a [ControlFlowGraph].
+pub type BlockId = usize;
+
+/// A run of statements that always execute one after the other. Statements that branch, such as
+/// `if` or `while`, end their block and stand for the evaluation of their condition.
+#[derive(Debug, Default)]
+pub struct BasicBlock<'a> {
+    pub stmnts: Vec<&'a Stmnt>,
+    pub successors: Vec<BlockId>,
+    /// The `return`, `break` or `continue` right before this block, which control never falls
+    /// through to reach it.
+    pub after_jump: Option<&'static str>,
+    /// Branch that a condition folding to a constant never takes.
+    pub dead: bool,
+}
+
+/// Control flow of a function body, with constant conditions only taking the branch they select.
+#[derive(Debug)]
+pub struct ControlFlowGraph<'a> {
+    pub blocks: Vec<BasicBlock<'a>>,
+    /// Where control ends up when it runs off the end of the body.
+    pub end: BlockId,
+}
+
+impl<'a> ControlFlowGraph<'a> {
+    pub const ENTRY: BlockId = 0;
+    /// Reached by every `return`.
+    pub const EXIT: BlockId = 1;
+
+    /// Builds the graph of `body`, where the `exhaustive` matches, whose cases cover every value
+    /// of their scrutinee, never skip all of their arms.
+    pub fn new(body: &'a Block, exhaustive: &[Position]) -> Self {
+        let mut builder = Builder {
+            blocks: vec![BasicBlock::default(), BasicBlock::default()],
+            loops: Vec::new(),
+            exhaustive,
+        };
+        let end = builder.block(body, Self::ENTRY);
+        ControlFlowGraph {
+            blocks: builder.blocks,
+            end,
+        }
+    }
+
+    /// Whether each block can be reached from the entry.
+    pub fn reachable(&self) -> Vec<bool> {
+        let mut reachable = vec![false; self.blocks.len()];
+        let mut pending = vec![Self::ENTRY];
+        while let Some(block) = pending.pop() {
+            if !std::mem::replace(&mut reachable[block], true) {
+                pending.extend(&self.blocks[block].successors);
+            }
+        }
+        reachable
+    }
+
+    /// Whether some path runs off the end of the body without returning.
+    pub fn falls_through(&self) -> bool {
+        self.reachable()[self.end]
+    }
+
+    /// First statement of each stretch of code that can never run, with the jump it follows when
+    /// there is one. Code inside a dead branch or nested in code already reported is left out.
+    pub fn unreachable(&self) -> Vec<(Position, Option<&'static str>)> {
+        let reachable = self.reachable();
+        let mut predecessors = vec![Vec::new(); self.blocks.len()];
+        for (id, block) in self.blocks.iter().enumerate() {
+            for successor in &block.successors {
+                predecessors[*successor].push(id);
+            }
+        }
+        // Blocks are created in source order, so a block's predecessors are settled before it,
+        // except for the back edges of loops and jumps out of them.
+        let mut covered = vec![false; self.blocks.len()];
+        let mut unreachable = Vec::new();
+        for (id, block) in self.blocks.iter().enumerate() {
+            if reachable[id] {
+                continue;
+            }
+            if block.dead || predecessors[id].iter().any(|&from| covered[from]) {
+                covered[id] = true;
+            } else if let Some(first) = block.stmnts.first() {
+                covered[id] = true;
+                unreachable.push((first.position(), block.after_jump));
+            }
+        }
+        unreachable
+    }
+}
+
+struct Builder<'a, 'e> {
+    blocks: Vec<BasicBlock<'a>>,
+    /// Where `continue` and `break` go in each enclosing loop.
+    loops: Vec<(BlockId, BlockId)>,
+    exhaustive: &'e [Position],
+}
+
+impl<'a> Builder<'a, '_> {
+    fn new_block(&mut self) -> BlockId {
+        self.blocks.push(BasicBlock::default());
+        self.blocks.len() - 1
+    }
+
+    fn edge(&mut self, from: BlockId, to: BlockId) {
+        self.blocks[from].successors.push(to);
+    }
+
+    /// Adds the statements of `block` starting in `current`, returning the block control is in
+    /// after them.
+    fn block(&mut self, block: &'a Block, mut current: BlockId) -> BlockId {
+        for stmnt in block {
+            current = self.stmnt(stmnt, current);
+        }
+        current
+    }
+
+    /// Ends `current` with a jump to `target`; what follows starts a block with no predecessors.
+    fn jump(
+        &mut self,
+        current: BlockId,
+        target: Option<BlockId>,
+        keyword: &'static str,
+    ) -> BlockId {
+        if let Some(target) = target {
+            self.edge(current, target);
+        }
+        let after = self.new_block();
+        self.blocks[after].after_jump = Some(keyword);
+        after
+    }
+
+    /// Block where the paths ending in `ends` meet again. It is created after the paths so blocks
+    /// stay in source order.
+    fn join(&mut self, ends: Vec<BlockId>) -> BlockId {
+        let after = self.new_block();
+        for end in ends {
+            self.edge(end, after);
+        }
+        after
+    }
+
+    /// Starts the body of a branch from `from`, unless the branch is `dead`.
+    fn branch(&mut self, from: BlockId, dead: bool) -> BlockId {
+        let start = self.new_block();
+        match dead {
+            true => self.blocks[start].dead = true,
+            false => self.edge(from, start),
+        }
+        start
+    }
+
+    fn stmnt(&mut self, stmnt: &'a Stmnt, current: BlockId) -> BlockId {
+        self.blocks[current].stmnts.push(stmnt);
+        match stmnt {
+            Stmnt::Return(..) => self.jump(current, Some(ControlFlowGraph::EXIT), "return"),
+            Stmnt::Break(_) => {
+                let target = self.loops.last().map(|(_, after)| *after);
+                self.jump(current, target, "break")
+            }
+            Stmnt::Continue(_) => {
+                let target = self.loops.last().map(|(next, _)| *next);
+                self.jump(current, target, "continue")
+            }
+            Stmnt::Block(body, _) => self.block(body, current),
+            Stmnt::If {
+                branches,
+                otherwise,
+                ..
+            } => {
+                // Where the next condition is tested, `None` once a condition is always true.
+                let mut test = Some(current);
+                let mut ends = Vec::new();
+                for (condition, body) in branches {
+                    let constant = constant_condition(condition);
+                    let from = test.unwrap_or(current);
+                    let start = self.branch(from, test.is_none() || constant == Some(false));
+                    ends.push(self.block(body, start));
+                    if constant == Some(true) {
+                        test = None;
+                    }
+                }
+                match otherwise {
+                    Some(body) => {
+                        let start = self.branch(test.unwrap_or(current), test.is_none());
+                        ends.push(self.block(body, start));
+                    }
+                    None => ends.extend(test),
+                }
+                self.join(ends)
+            }
+            Stmnt::Match {
+                cases,
+                default,
+                position,
+                ..
+            } => {
+                let mut ends = Vec::new();
+                for body in cases.iter().map(|case| &case.body).chain(default) {
+                    let start = self.branch(current, false);
+                    ends.push(self.block(body, start));
+                }
+                if !match_is_total(cases, default, *position, self.exhaustive) {
+                    ends.push(current);
+                }
+                self.join(ends)
+            }
+            Stmnt::While {
+                condition, body, ..
+            } => {
+                let head = self.new_block();
+                self.edge(current, head);
+                self.do_loop(head, head, constant_condition(condition), body)
+            }
+            Stmnt::For {
+                condition,
+                step,
+                body,
+                ..
+            } => {
+                let head = self.new_block();
+                self.edge(current, head);
+                let next = self.new_block();
+                self.blocks[next].stmnts.push(step);
+                self.edge(next, head);
+                self.do_loop(head, next, constant_condition(condition), body)
+            }
+            Stmnt::Decl(_) | Stmnt::Assign(_) | Stmnt::Expr(..) => current,
+        }
+    }
+
+    /// Adds a loop testing its condition in `head`, where `continue` goes to `next`.
+    fn do_loop(
+        &mut self,
+        head: BlockId,
+        next: BlockId,
+        constant: Option<bool>,
+        body: &'a Block,
+    ) -> BlockId {
+        let after = self.new_block();
+        if constant != Some(true) {
+            self.edge(head, after);
+        }
+        let start = self.branch(head, constant == Some(false));
+        self.loops.push((next, after));
+        let end = self.block(body, start);
+        self.loops.pop();
+        self.edge(end, next);
+        after
+    }
+}
@@ -0,0 +1,124 @@
+use crate::front::parser::syntax_tree::Position;
+use crate::front::semantics::layout::Layout;
+use crate::front::semantics::lints::Level;
+use crate::front::semantics::semantic_errors::{SemanticError, SemanticWarning};
+
+/// A semantic error together with the token it was found at.
+#[derive(Debug)]
+pub struct Diagnostic {
+    pub position: Position,
+    pub error: SemanticError,
+    /// Earlier declaration the error refers to, e.g. the original of a redeclared name.
+    pub related: Option<Position>,
+}
+
+/// Suspicious code found at a token, which does not stop compilation by itself.
+#[derive(Debug)]
+pub struct Warning {
+    pub position: Position,
+    pub warning: SemanticWarning,
+    /// Level set by an attribute on the enclosing function, if any.
+    pub level: Option<Level>,
+}
+
+/// Errors and warnings found so far. Checking carries on after each error, so a single run
+/// reports every error in the file.
+#[derive(Debug, Default)]
+pub struct Diagnostics {
+    errors: Vec<Diagnostic>,
+    warnings: Vec<Warning>,
+}
+
+/// Everything found in a file, each kind in source order.
+#[derive(Debug)]
+pub struct Report {
+    pub errors: Vec<Diagnostic>,
+    pub warnings: Vec<Warning>,
+    /// Memory layout of every structure and enumeration that has one, and of every generic
+    /// structure for each list of type arguments it is used with.
+    pub layouts: Vec<Layout>,
+    /// Every generic function with the type arguments of each of its instances, as `max<int>`.
+    pub instances: Vec<String>,
+    /// The tables of types, functions and bindings the checker built, for debugging.
+    pub tables: String,
+}
+
+impl Diagnostics {
+    pub fn report(&mut self, position: Position, error: SemanticError) {
+        self.errors.push(Diagnostic {
+            position,
+            error,
+            related: None,
+        });
+    }
+
+    pub fn report_related(&mut self, position: Position, error: SemanticError, related: Position) {
+        self.errors.push(Diagnostic {
+            position,
+            error,
+            related: Some(related),
+        });
+    }
+
+    pub fn warn(&mut self, position: Position, warning: SemanticWarning, level: Option<Level>) {
+        self.warnings.push(Warning {
+            position,
+            warning,
+            level,
+        });
+    }
+
+    /// Keeps the first declaration of each name, reporting every later one as redeclaring it.
+    pub fn first_declarations<'a, T>(
+        &mut self,
+        declarations: impl IntoIterator<Item = (&'a str, Position, T)>,
+    ) -> Vec<T> {
+        let mut seen: Vec<(&str, Position)> = Vec::new();
+        let mut kept = Vec::new();
+        for (name, position, declaration) in declarations {
+            match seen.iter().find(|(seen_name, _)| *seen_name == name) {
+                Some((_, original)) => self.report_related(
+                    position,
+                    SemanticError::RedeclaredIdentifier(name.to_string(), name.to_string()),
+                    *original,
+                ),
+                None => {
+                    seen.push((name, position));
+                    kept.push(declaration);
+                }
+            }
+        }
+        kept
+    }
+
+    /// Sorts errors and warnings by position. Those at the same position keep the order they
+    /// were found in. The body of a generic function is checked once per instance, so the same
+    /// error or warning found again at the same position is only kept once.
+    pub fn into_report(mut self) -> Report {
+        self.errors.sort_by_key(|diagnostic| diagnostic.position);
+        self.warnings.sort_by_key(|warning| warning.position);
+        let mut seen = Vec::new();
+        self.errors.retain(|diagnostic| {
+            let key = (diagnostic.position, diagnostic.error.to_string());
+            !seen.contains(&key) && {
+                seen.push(key);
+                true
+            }
+        });
+        seen.clear();
+        self.warnings.retain(|warning| {
+            let key = (warning.position, warning.warning.to_string());
+            !seen.contains(&key) && {
+                seen.push(key);
+                true
+            }
+        });
+        Report {
+            errors: self.errors,
+            warnings: self.warnings,
+            layouts: Vec::new(),
+            instances: Vec::new(),
+            tables: String::new(),
+        }
+    }
+}
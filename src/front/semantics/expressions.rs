@@ -0,0 +1,436 @@
+use crate::front::lexer::reserved::Operator;
+use crate::front::lexer::tokens::Literal;
+use crate::front::parser::grammar::DataType;
+use crate::front::parser::syntax_tree::{Expr, Position};
+use crate::front::semantics::fold::{fold, fold_binary, fold_unary, long_constant, FoldError};
+use crate::front::semantics::generics::{substitute, Substitution};
+use crate::front::semantics::semantic_errors::SemanticError;
+use crate::front::semantics::suggestions::closest_name;
+use crate::front::semantics::types::{suggest_type, IdentifierTypeCell, TypeChecker};
+
+pub fn is_numeric(data_type: &DataType) -> bool {
+    matches!(
+        data_type,
+        DataType::Int | DataType::Long | DataType::Float | DataType::Double
+    )
+}
+
+/// Whether `literal` is a numeric zero.
+fn is_zero(literal: &Literal) -> bool {
+    matches!(literal, Literal::Int(0) | Literal::Long(0))
+        || matches!(literal, Literal::Float(value) if *value == 0.0)
+        || matches!(literal, Literal::Double(value) if *value == 0.0)
+}
+
+/// Whether a value of type `from` can be used where `to` is expected without an explicit cast,
+/// i.e. the types are the same or every `from` value is exactly a `to` value: `int` widens to
+/// `long` and `double`, and `float` to `double`. `int` to `float` and `long` to `float` or
+/// `double` would round large values, so they need a cast.
+pub fn widens_to(from: &DataType, to: &DataType) -> bool {
+    from == to
+        || matches!(
+            (from, to),
+            (DataType::Int, DataType::Long | DataType::Double)
+                | (DataType::Float, DataType::Double)
+        )
+}
+
+/// Narrowest numeric type both operands widen to, so `int` and `float` join as `double`, while
+/// `long` and `float` have no common type.
+pub(crate) fn numeric_join(lhs: &DataType, rhs: &DataType) -> Option<DataType> {
+    [
+        DataType::Int,
+        DataType::Long,
+        DataType::Float,
+        DataType::Double,
+    ]
+    .into_iter()
+    .find(|joined| widens_to(lhs, joined) && widens_to(rhs, joined))
+}
+
+impl TypeChecker<'_> {
+    /// Type of `expr`, computed bottom-up from its operands. Every call, field access and
+    /// operator met on the way is validated.
+    ///
+    /// Errors are reported where they are found and make the expression's type unknown, `None`.
+    /// An unknown operand poisons the expressions built on it, which are then not checked any
+    /// further so a single mistake is reported once.
+    pub(crate) fn expression_type(&mut self, expr: &Expr) -> Option<DataType> {
+        match expr {
+            Expr::Literal(literal, _) => Some(DataType::from(literal.to_owned())),
+            Expr::Identifier(identifier, position) => self.identifier_type(identifier, *position),
+            Expr::Call {
+                callee,
+                args,
+                position,
+            } => self.check_call(callee, args, *position),
+            Expr::Field {
+                base,
+                field,
+                position,
+            } => {
+                let base_type = self.expression_type(base)?;
+                self.field_type(&base_type, field)
+                    .map_err(|error| self.diagnostics.report(*position, *error))
+                    .ok()
+            }
+            Expr::Index {
+                base,
+                index,
+                position,
+            } => {
+                let base_type = self.expression_type(base);
+                self.expression_type(index);
+                // There are no array types yet, so nothing can be indexed.
+                if let Some(base_type) = base_type {
+                    self.diagnostics.report(
+                        *position,
+                        SemanticError::UnsupportedOperation("[]".to_string(), base_type),
+                    );
+                }
+                None
+            }
+            Expr::Unary {
+                operator,
+                operand,
+                position,
+            } => {
+                let operand_type = self.expression_type(operand)?;
+                match (operator, &operand_type) {
+                    (Operator::Negation, DataType::Bool) => Some(operand_type),
+                    (Operator::Subtraction, data_type) if is_numeric(data_type) => {
+                        if let Some(operand) = fold(operand) {
+                            self.report_fold_error(fold_unary(operator, operand), *position);
+                        }
+                        Some(operand_type)
+                    }
+                    _ => {
+                        self.diagnostics.report(
+                            *position,
+                            SemanticError::InvalidUnaryOperand(operator.to_string(), operand_type),
+                        );
+                        None
+                    }
+                }
+            }
+            Expr::Cast {
+                operand,
+                data_type,
+                position,
+            } => {
+                let operand_type = self.expression_type(operand);
+                self.mark_type_used(data_type);
+                if let DataType::Identifier(identifier) = data_type {
+                    if !self.is_declared_type(identifier) {
+                        let suggestion = self.suggest_type(identifier);
+                        self.diagnostics.report(
+                            *position,
+                            SemanticError::UndeclaredType(identifier.clone(), suggestion),
+                        );
+                        return None;
+                    }
+                }
+                // The result has the target type even when the cast is rejected, so the
+                // surrounding expression is still checked.
+                if let Some(operand_type) = operand_type {
+                    if !self.cast_allowed(&operand_type, data_type) {
+                        self.diagnostics.report(
+                            *position,
+                            SemanticError::InvalidCast(operand_type, data_type.clone()),
+                        );
+                    }
+                }
+                Some(data_type.clone())
+            }
+            Expr::Binary {
+                operator: Operator::Assignment,
+                position,
+                ..
+            } => {
+                self.diagnostics
+                    .report(*position, SemanticError::AssignmentInExpression);
+                None
+            }
+            Expr::Binary {
+                operator,
+                lhs,
+                rhs,
+                position,
+            } => {
+                // Both sides are checked even if one of them is already unknown.
+                let lhs_type = self.expression_type(lhs);
+                let rhs_type = self.expression_type(rhs);
+                let (lhs_type, rhs_type) = (lhs_type?, rhs_type?);
+                // Dividing by a constant zero is an error even when the dividend is not constant.
+                let divisor = fold(rhs);
+                if *operator == Operator::Division && divisor.as_ref().is_some_and(is_zero) {
+                    self.diagnostics
+                        .report(*position, SemanticError::DivisionByZero);
+                }
+                let result = self.binary_type(operator, &lhs_type, &rhs_type);
+                match (&result, fold(lhs), divisor) {
+                    (None, ..) => self.diagnostics.report(
+                        *position,
+                        SemanticError::InvalidOperands(operator.to_string(), lhs_type, rhs_type),
+                    ),
+                    (Some(_), Some(lhs), Some(rhs)) => {
+                        self.report_fold_error(fold_binary(operator, lhs, rhs), *position)
+                    }
+                    _ => {}
+                }
+                result
+            }
+        }
+    }
+
+    /// Reports an operation over constants whose result overflows its type. A division by zero
+    /// is reported on its own, whether or not the dividend is constant.
+    fn report_fold_error(&mut self, result: Result<Literal, FoldError>, position: Position) {
+        if let Err(FoldError::Overflow(data_type)) = result {
+            self.diagnostics
+                .report(position, SemanticError::ConstantOverflow(data_type));
+        }
+    }
+
+    /// Checks a value stored in a variable of type `target`. A `long` constant stored in an
+    /// `int` is allowed when it fits, so the caller skips its usual type check on `true`.
+    pub(crate) fn check_int_range(
+        &mut self,
+        target: &DataType,
+        value: &Expr,
+        position: Position,
+    ) -> bool {
+        let Some(constant) = long_constant(value).filter(|_| *target == DataType::Int) else {
+            return false;
+        };
+        if i32::try_from(constant).is_err() {
+            self.diagnostics.report(
+                position,
+                SemanticError::ConstantOutOfRange(constant.to_string(), DataType::Int),
+            );
+        }
+        true
+    }
+
+    /// Variables resolve to their declared type and bare enum variants to their enumeration.
+    /// Variables whose declared type does not exist were already reported and stay unknown.
+    fn identifier_type(&mut self, identifier: &str, position: Position) -> Option<DataType> {
+        if let Some(var) = self.scopes.find(identifier) {
+            let data_type = var.data_type.clone();
+            self.scopes.mark_used(identifier);
+            if !self.scopes.is_assigned(identifier) {
+                self.diagnostics.report(
+                    position,
+                    SemanticError::UnassignedVariable(identifier.to_string()),
+                );
+                // Reported at the first read only.
+                self.scopes.mark_assigned(identifier);
+            }
+            return (!self.is_poisoned(&data_type)).then_some(data_type);
+        }
+        let variant_of = self
+            .find_variant(identifier)
+            .map(|(enumeration, variant)| (DataType::Identifier(enumeration.to_string()), variant));
+        if let Some((enumeration, variant)) = &variant_of {
+            self.mark_type_used(enumeration);
+            if !variant.parameters().is_empty() {
+                self.diagnostics.report(
+                    position,
+                    SemanticError::VariantNeedsPayload(identifier.to_string()),
+                );
+                return None;
+            }
+        }
+        let variant_of = variant_of.map(|(enumeration, _)| enumeration);
+        if variant_of.is_none() {
+            if let Some(declared_at) = self.later_declaration(identifier, position) {
+                self.diagnostics.report_related(
+                    position,
+                    SemanticError::UsedBeforeDeclaration(identifier.to_string()),
+                    declared_at,
+                );
+                return None;
+            }
+            let error = if self.initialising.as_deref() == Some(identifier) {
+                SemanticError::UndefinedVariable(identifier.to_string())
+            } else {
+                SemanticError::UndeclaredIdentifier(
+                    identifier.to_string(),
+                    self.suggest_identifier(identifier),
+                )
+            };
+            self.diagnostics.report(position, error);
+        }
+        variant_of
+    }
+
+    /// Whether `data_type` names a structure or enumeration that was never declared or is not
+    /// given as many type arguments as it takes, or is a type parameter standing for no type in
+    /// particular. The declaration using it has already been reported.
+    pub(crate) fn is_poisoned(&self, data_type: &DataType) -> bool {
+        let (identifier, arguments) = match data_type {
+            DataType::Identifier(identifier) => (identifier, [].as_slice()),
+            DataType::Applied(identifier, arguments) => (identifier, arguments.as_slice()),
+            DataType::Parameter(_) => return true,
+            _ => return false,
+        };
+        self.type_params(identifier)
+            .is_none_or(|type_params| type_params.len() != arguments.len())
+            || arguments.iter().any(|argument| self.is_poisoned(argument))
+    }
+
+    pub(crate) fn field_type(
+        &self,
+        base_type: &DataType,
+        field: &str,
+    ) -> Result<DataType, Box<SemanticError>> {
+        let (structure, arguments) = match base_type {
+            DataType::Identifier(structure) => (Some(structure), [].as_slice()),
+            DataType::Applied(structure, arguments) => (Some(structure), arguments.as_slice()),
+            _ => (None, [].as_slice()),
+        };
+        let fields = structure.and_then(|structure| {
+            self.valid_id_types
+                .iter()
+                .find_map(|valid_t| match valid_t {
+                    IdentifierTypeCell::StructType {
+                        identifier,
+                        type_params,
+                        fields,
+                    } if identifier == structure => Some((type_params, fields)),
+                    _ => None,
+                })
+        });
+        let (type_params, fields) = fields.ok_or_else(|| {
+            Box::from(SemanticError::InvalidFieldAccess(
+                field.to_string(),
+                base_type.clone(),
+            ))
+        })?;
+        // Fields of a generic structure have the types it was given for its parameters.
+        let substitution: Substitution = type_params
+            .iter()
+            .cloned()
+            .zip(arguments.iter().cloned())
+            .collect();
+        fields
+            .iter()
+            .find(|cell| cell.identifier == field)
+            .map(|cell| substitute(&cell.data_type, &substitution))
+            .ok_or_else(|| {
+                Box::from(SemanticError::UndeclaredField(
+                    base_type.to_string(),
+                    field.to_string(),
+                    closest_name(field, fields.iter().map(|cell| cell.identifier.as_str())),
+                ))
+            })
+    }
+
+    /// Result type of a binary operation, `None` when the operator does not apply to the operands.
+    ///
+    /// - `+ - * /` take two numbers, the narrower one being widened to the type of the other;
+    /// - `+` also concatenates a `string` with a `string` or a `character`, in either order;
+    /// - `< <= > >=` compare two numbers or two characters;
+    /// - `== !=` compare two numbers, or any two values of the same type except structures;
+    /// - `&& ||` take two `boolean`s.
+    pub(crate) fn binary_type(
+        &self,
+        operator: &Operator,
+        lhs: &DataType,
+        rhs: &DataType,
+    ) -> Option<DataType> {
+        let same_type = lhs == rhs;
+        match operator {
+            Operator::Sum
+                if matches!(
+                    (lhs, rhs),
+                    (DataType::Str, DataType::Str | DataType::Char)
+                        | (DataType::Char, DataType::Str)
+                ) =>
+            {
+                Some(DataType::Str)
+            }
+            Operator::Sum
+            | Operator::Subtraction
+            | Operator::Multiplication
+            | Operator::Division => numeric_join(lhs, rhs),
+            Operator::GreaterThan
+            | Operator::GreaterThanOrEqual
+            | Operator::LessThan
+            | Operator::LessThanOrEqual
+                if numeric_join(lhs, rhs).is_some() || (same_type && *lhs == DataType::Char) =>
+            {
+                Some(DataType::Bool)
+            }
+            Operator::Equality | Operator::Inequality
+                if numeric_join(lhs, rhs).is_some()
+                    || (same_type && *lhs != DataType::Void && !self.is_struct(lhs)) =>
+            {
+                Some(DataType::Bool)
+            }
+            Operator::And | Operator::Or if same_type && *lhs == DataType::Bool => {
+                Some(DataType::Bool)
+            }
+            _ => None,
+        }
+    }
+
+    /// Explicit conversions: any number to any other number, `character` to and from the
+    /// integer types, and `boolean` or enumeration values to the integer types.
+    fn cast_allowed(&self, from: &DataType, to: &DataType) -> bool {
+        let integer = |data_type: &DataType| matches!(data_type, DataType::Int | DataType::Long);
+        from == to
+            || (is_numeric(from) && is_numeric(to))
+            || (*from == DataType::Char && integer(to))
+            || (integer(from) && *to == DataType::Char)
+            || (*from == DataType::Bool && integer(to))
+            || (self.is_enum(from) && integer(to))
+    }
+
+    /// Visible variable or enum variant most likely meant by the undeclared `identifier`.
+    pub(crate) fn suggest_identifier(&self, identifier: &str) -> Option<String> {
+        let variants = self
+            .valid_id_types
+            .iter()
+            .flat_map(|valid_t| match valid_t {
+                IdentifierTypeCell::EnumType { variants, .. } => variants.as_slice(),
+                IdentifierTypeCell::StructType { .. } => &[],
+            });
+        closest_name(
+            identifier,
+            self.scopes
+                .names()
+                .chain(variants.map(|variant| variant.identifier.as_str())),
+        )
+    }
+
+    /// Builtin or declared type most likely meant by the undeclared `type_identifier`.
+    pub(crate) fn suggest_type(&self, type_identifier: &str) -> Option<String> {
+        suggest_type(self.valid_id_types, type_identifier)
+    }
+
+    pub(crate) fn is_declared_type(&self, type_identifier: &str) -> bool {
+        self.valid_id_types.iter().any(|valid_t| match valid_t {
+            IdentifierTypeCell::StructType { identifier, .. }
+            | IdentifierTypeCell::EnumType { identifier, .. } => identifier == type_identifier,
+        })
+    }
+
+    fn is_enum(&self, data_type: &DataType) -> bool {
+        match data_type {
+            DataType::Identifier(identifier) => self.enum_variants(identifier).is_some(),
+            _ => false,
+        }
+    }
+
+    fn is_struct(&self, data_type: &DataType) -> bool {
+        let structure = match data_type {
+            DataType::Identifier(structure) | DataType::Applied(structure, _) => structure,
+            _ => return false,
+        };
+        self.valid_id_types.iter().any(|valid_t| match valid_t {
+            IdentifierTypeCell::StructType { identifier, .. } => identifier == structure,
+            _ => false,
+        })
+    }
+}
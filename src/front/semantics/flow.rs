@@ -0,0 +1,156 @@
+use crate::front::parser::syntax_tree::{Block, Expr, Position, Stmnt};
+use crate::front::semantics::fold::constant_condition;
+use crate::front::semantics::functions::always_jumps;
+use crate::front::semantics::semantic_errors::{SemanticError, SemanticWarning};
+use crate::front::semantics::types::{TypeCell, TypeChecker};
+
+/// Variables definitely assigned where alternative paths through a statement meet again: those
+/// assigned on every path that does not jump away with `return`, `break` or `continue`.
+#[derive(Debug, Default)]
+pub(crate) struct Join(Option<Vec<bool>>);
+
+impl Join {
+    /// Adds the state at the end of one path.
+    pub(crate) fn path(&mut self, assignments: Vec<bool>) {
+        self.0 = Some(match self.0.take() {
+            Some(joined) => joined
+                .into_iter()
+                .zip(assignments)
+                .map(|(joined, assigned)| joined && assigned)
+                .collect(),
+            None => assignments,
+        });
+    }
+
+    /// State after the statement. When every path returns, the code that follows is unreachable
+    /// and `before` is used so it is checked as if the statement were skipped.
+    pub(crate) fn finish(self, before: Vec<bool>) -> Vec<bool> {
+        self.0.unwrap_or(before)
+    }
+}
+
+/// Every variable declared in `block` and its nested blocks, with where it is declared.
+pub(crate) fn block_declarations(block: &Block) -> Vec<(String, Position)> {
+    let mut declarations = Vec::new();
+    for stmnt in block {
+        match stmnt {
+            Stmnt::Decl(decl) => declarations.push((decl.identifier.clone(), decl.position)),
+            Stmnt::If {
+                branches,
+                otherwise,
+                ..
+            } => {
+                for (_, body) in branches {
+                    declarations.extend(block_declarations(body));
+                }
+                if let Some(body) = otherwise {
+                    declarations.extend(block_declarations(body));
+                }
+            }
+            Stmnt::Match { cases, default, .. } => {
+                for case in cases {
+                    declarations.extend(block_declarations(&case.body));
+                }
+                if let Some(body) = default {
+                    declarations.extend(block_declarations(body));
+                }
+            }
+            Stmnt::For { init, body, .. } => {
+                declarations.push((init.identifier.clone(), init.position));
+                declarations.extend(block_declarations(body));
+            }
+            Stmnt::While { body, .. } | Stmnt::Block(body, _) => {
+                declarations.extend(block_declarations(body))
+            }
+            Stmnt::Assign(_)
+            | Stmnt::Return(..)
+            | Stmnt::Break(_)
+            | Stmnt::Continue(_)
+            | Stmnt::Expr(..) => {}
+        }
+    }
+    declarations
+}
+
+impl TypeChecker<'_> {
+    /// Checks `body` as one of several alternative paths, all starting from `before`.
+    pub(crate) fn check_branch(&mut self, body: &Block, before: &[bool], join: &mut Join) {
+        self.scopes.restore_assignments(before);
+        self.check_block(body);
+        if !always_jumps(body, &self.exhaustive_matches) {
+            join.path(self.scopes.assignments());
+        }
+    }
+
+    /// Checks the body of a `case` like [Self::check_branch], with the values its pattern
+    /// `bindings` declared around the body.
+    pub(crate) fn check_case_branch(
+        &mut self,
+        body: &Block,
+        bindings: Vec<(TypeCell, Position)>,
+        before: &[bool],
+        join: &mut Join,
+    ) {
+        self.scopes.restore_assignments(before);
+        self.scopes.enter_scope();
+        for (cell, position) in bindings {
+            self.declare(cell, position, true);
+        }
+        self.check_block(body);
+        self.exit_scope(&[]);
+        if !always_jumps(body, &self.exhaustive_matches) {
+            join.path(self.scopes.assignments());
+        }
+    }
+
+    /// Checks the body and `step` of a loop testing `condition`. The loop may run any number of
+    /// times including none, so it assigns nothing definitely for the code after it, unless the
+    /// condition is always true: the loop then only ends at a `break`, and the code after it sees
+    /// what was assigned before every one of them.
+    pub(crate) fn check_loop_body(&mut self, condition: &Expr, body: &Block, step: Option<&Stmnt>) {
+        let before = self.scopes.assignments();
+        self.loop_exits.push(Join::default());
+        self.check_block(body);
+        if let Some(step) = step {
+            self.check_stmnt(step);
+        }
+        let exits = self.loop_exits.pop().unwrap_or_default();
+        match constant_condition(condition) {
+            Some(true) => self.scopes.restore_assignments(&exits.finish(before)),
+            _ => self.scopes.restore_assignments(&before),
+        }
+    }
+
+    pub(crate) fn check_jump(&mut self, keyword: &str, position: Position) {
+        if self.loop_exits.is_empty() {
+            self.diagnostics.report(
+                position,
+                SemanticError::JumpOutsideLoop(keyword.to_string()),
+            );
+        }
+    }
+
+    /// Warns about an `if`, `elif` or `while` condition that always has the same value, leaving
+    /// one of its branches dead.
+    pub(crate) fn warn_constant_condition(&mut self, condition: &Expr) {
+        if let Some(value) = constant_condition(condition) {
+            self.warn(
+                condition.position(),
+                SemanticWarning::ConstantCondition(value),
+            );
+        }
+    }
+
+    /// Declaration of `identifier` further down the current function, when `identifier` is used
+    /// at `position` before it.
+    pub(crate) fn later_declaration(
+        &self,
+        identifier: &str,
+        position: Position,
+    ) -> Option<Position> {
+        self.function_declarations
+            .iter()
+            .find(|(declared, declared_at)| declared == identifier && *declared_at > position)
+            .map(|(_, declared_at)| *declared_at)
+    }
+}
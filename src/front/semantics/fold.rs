@@ -0,0 +1,231 @@
+use crate::front::lexer::reserved::Operator;
+use crate::front::lexer::tokens::{Bool, Literal};
+use crate::front::parser::grammar::DataType;
+use crate::front::parser::syntax_tree::Expr;
+use crate::front::semantics::expressions::numeric_join;
+use std::cmp::Ordering;
+
+/// Why an operation over constants has no value.
+#[derive(Debug, PartialEq)]
+pub enum FoldError {
+    /// The operation is not evaluated at compile time, such as `+` on strings.
+    NotConstant,
+    DivisionByZero,
+    /// The result does not fit in the type of the operation.
+    Overflow(DataType),
+}
+
+fn boolean(value: bool) -> Literal {
+    Literal::Bool(if value { Bool::True } else { Bool::False })
+}
+
+/// A folded number widened to the type both operands of an operation share.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+enum Number {
+    Int(i64),
+    Long(i64),
+    Float(f64),
+    Double(f64),
+}
+
+impl Number {
+    fn of(literal: &Literal) -> Option<Self> {
+        match literal {
+            Literal::Int(value) => Some(Number::Int(i64::from(*value))),
+            Literal::Long(value) => Some(Number::Long(*value)),
+            Literal::Float(value) => Some(Number::Float(f64::from(*value))),
+            Literal::Double(value) => Some(Number::Double(*value)),
+            _ => None,
+        }
+    }
+
+    fn as_float(&self) -> f64 {
+        match self {
+            Number::Int(value) | Number::Long(value) => *value as f64,
+            Number::Float(value) | Number::Double(value) => *value,
+        }
+    }
+
+    /// Both operands converted to the type of the operation, the narrowest one they both widen
+    /// to, `None` when they have none.
+    fn join(lhs: Self, rhs: Self) -> Option<(Self, Self)> {
+        let joined = numeric_join(&lhs.data_type(), &rhs.data_type())?;
+        let widen = |number: Self| match (number, &joined) {
+            (Number::Int(value), DataType::Long) => Number::Long(value),
+            (number, DataType::Double) => Number::Double(number.as_float()),
+            (number, _) => number,
+        };
+        Some((widen(lhs), widen(rhs)))
+    }
+
+    fn data_type(&self) -> DataType {
+        match self {
+            Number::Int(_) => DataType::Int,
+            Number::Long(_) => DataType::Long,
+            Number::Float(_) => DataType::Float,
+            Number::Double(_) => DataType::Double,
+        }
+    }
+
+    /// Fails when an integer result does not fit in its type.
+    fn into_literal(self) -> Result<Literal, FoldError> {
+        match self {
+            Number::Int(value) => i32::try_from(value)
+                .map(Literal::Int)
+                .map_err(|_| FoldError::Overflow(DataType::Int)),
+            Number::Long(value) => Ok(Literal::Long(value)),
+            Number::Float(value) => Ok(Literal::Float(value as f32)),
+            Number::Double(value) => Ok(Literal::Double(value)),
+        }
+    }
+}
+
+/// Value of `expr` when it only combines literals, `None` when it depends on anything else or
+/// cannot be computed, as for a division by zero or a result overflowing its type.
+///
+/// `&&` and `||` short-circuit, so `false && x` is `false` whatever `x` is.
+pub fn fold(expr: &Expr) -> Option<Literal> {
+    match expr {
+        Expr::Literal(literal, _) => Some(literal.clone()),
+        Expr::Unary {
+            operator, operand, ..
+        } => fold_unary(operator, fold(operand)?).ok(),
+        Expr::Binary {
+            operator: operator @ (Operator::And | Operator::Or),
+            lhs,
+            rhs,
+            ..
+        } => {
+            let decisive = Literal::Bool(match operator {
+                Operator::And => Bool::False,
+                _ => Bool::True,
+            });
+            let lhs = fold(lhs);
+            if lhs.as_ref() == Some(&decisive) {
+                return lhs;
+            }
+            match (lhs?, fold(rhs)?) {
+                (Literal::Bool(_), rhs @ Literal::Bool(_)) => Some(rhs),
+                _ => None,
+            }
+        }
+        Expr::Binary {
+            operator, lhs, rhs, ..
+        } => fold_binary(operator, fold(lhs)?, fold(rhs)?).ok(),
+        _ => None,
+    }
+}
+
+/// Applies a unary `operator` to a constant `operand`.
+pub fn fold_unary(operator: &Operator, operand: Literal) -> Result<Literal, FoldError> {
+    match (operator, operand) {
+        (Operator::Negation, Literal::Bool(value)) => Ok(boolean(value == Bool::False)),
+        (Operator::Subtraction, literal) => {
+            let number = Number::of(&literal).ok_or(FoldError::NotConstant)?;
+            let overflow = FoldError::Overflow(number.data_type());
+            match number {
+                Number::Int(value) => Number::Int(value.checked_neg().ok_or(overflow)?),
+                Number::Long(value) => Number::Long(value.checked_neg().ok_or(overflow)?),
+                Number::Float(value) => Number::Float(-value),
+                Number::Double(value) => Number::Double(-value),
+            }
+            .into_literal()
+        }
+        _ => Err(FoldError::NotConstant),
+    }
+}
+
+/// Applies a binary `operator` to constant operands, widening numbers to their common type.
+/// `&&` and `||` are folded by [fold] since they may not need their right operand.
+pub fn fold_binary(operator: &Operator, lhs: Literal, rhs: Literal) -> Result<Literal, FoldError> {
+    if let (Some(lhs), Some(rhs)) = (Number::of(&lhs), Number::of(&rhs)) {
+        let (lhs, rhs) = Number::join(lhs, rhs).ok_or(FoldError::NotConstant)?;
+        return match operator {
+            Operator::Sum
+            | Operator::Subtraction
+            | Operator::Multiplication
+            | Operator::Division => arithmetic(operator, lhs, rhs)?.into_literal(),
+            _ => compare(
+                operator,
+                lhs.partial_cmp(&rhs).ok_or(FoldError::NotConstant)?,
+            ),
+        };
+    }
+    match operator {
+        Operator::Equality | Operator::Inequality if lhs.to_string() == rhs.to_string() => {
+            compare(operator, Ordering::Equal)
+        }
+        Operator::Equality | Operator::Inequality => compare(operator, Ordering::Less),
+        _ => Err(FoldError::NotConstant),
+    }
+}
+
+fn arithmetic(operator: &Operator, lhs: Number, rhs: Number) -> Result<Number, FoldError> {
+    let overflow = FoldError::Overflow(lhs.data_type());
+    match (lhs, rhs) {
+        (Number::Int(_) | Number::Long(_), Number::Int(0) | Number::Long(0))
+            if *operator == Operator::Division =>
+        {
+            Err(FoldError::DivisionByZero)
+        }
+        (Number::Int(lhs), Number::Int(rhs)) => {
+            integer(operator, lhs, rhs).map(Number::Int).ok_or(overflow)
+        }
+        (Number::Long(lhs), Number::Long(rhs)) => integer(operator, lhs, rhs)
+            .map(Number::Long)
+            .ok_or(overflow),
+        (Number::Float(lhs), Number::Float(rhs)) => float(operator, lhs, rhs).map(Number::Float),
+        (Number::Double(lhs), Number::Double(rhs)) => float(operator, lhs, rhs).map(Number::Double),
+        _ => Err(FoldError::NotConstant),
+    }
+}
+
+/// `None` when the result overflows an `i64`; [Number::into_literal] checks the `int` range.
+fn integer(operator: &Operator, lhs: i64, rhs: i64) -> Option<i64> {
+    match operator {
+        Operator::Sum => lhs.checked_add(rhs),
+        Operator::Subtraction => lhs.checked_sub(rhs),
+        Operator::Multiplication => lhs.checked_mul(rhs),
+        _ => lhs.checked_div(rhs),
+    }
+}
+
+fn float(operator: &Operator, lhs: f64, rhs: f64) -> Result<f64, FoldError> {
+    match operator {
+        Operator::Sum => Ok(lhs + rhs),
+        Operator::Subtraction => Ok(lhs - rhs),
+        Operator::Multiplication => Ok(lhs * rhs),
+        _ if rhs == 0.0 => Err(FoldError::DivisionByZero),
+        _ => Ok(lhs / rhs),
+    }
+}
+
+fn compare(operator: &Operator, ordering: Ordering) -> Result<Literal, FoldError> {
+    let result = match operator {
+        Operator::Equality => ordering == Ordering::Equal,
+        Operator::Inequality => ordering != Ordering::Equal,
+        Operator::LessThan => ordering == Ordering::Less,
+        Operator::LessThanOrEqual => ordering != Ordering::Greater,
+        Operator::GreaterThan => ordering == Ordering::Greater,
+        Operator::GreaterThanOrEqual => ordering != Ordering::Less,
+        _ => return Err(FoldError::NotConstant),
+    };
+    Ok(boolean(result))
+}
+
+/// Value of a condition that folds to a constant `boolean`.
+pub fn constant_condition(condition: &Expr) -> Option<bool> {
+    match fold(condition)? {
+        Literal::Bool(value) => Some(value == Bool::True),
+        _ => None,
+    }
+}
+
+/// Value of an expression stored in an `int` when it is a `long` constant. A `long` literal
+/// small enough for an `int`, such as `-2147483648`, is accepted there.
+pub fn long_constant(value: &Expr) -> Option<i64> {
+    match fold(value)? {
+        Literal::Long(value) => Some(value),
+        _ => None,
+    }
+}
@@ -0,0 +1,309 @@
+use crate::front::parser::grammar::DataType;
+use crate::front::parser::syntax_tree::{
+    Block, Case, Expr, FuncDecl, Item, Pattern, Position, Stmnt, SyntaxTree,
+};
+use crate::front::semantics::diagnostics::Diagnostics;
+use crate::front::semantics::expressions::widens_to;
+use crate::front::semantics::semantic_errors::SemanticError;
+use crate::front::semantics::types::{TypeCell, TypeChecker, TypeTable};
+
+pub type FunctionTable = Vec<FuncSignature>;
+
+/// Function a program starts running from.
+pub const ENTRY_POINT: &str = "main";
+
+/// What a program is compiled into.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum Target {
+    /// A program run from its [ENTRY_POINT].
+    #[default]
+    Executable,
+    /// Functions used by other programs, which has no entry point.
+    Library,
+}
+
+#[derive(Debug, Clone)]
+pub struct FuncSignature {
+    pub(crate) identifier: String,
+    /// Type parameters of a generic function, inferred from the arguments of each call.
+    pub(crate) type_params: Vec<String>,
+    pub(crate) return_type: DataType,
+    pub(crate) arguments: TypeTable,
+}
+
+impl From<&FuncDecl> for FuncSignature {
+    fn from(func: &FuncDecl) -> Self {
+        FuncSignature {
+            identifier: func.identifier.clone(),
+            type_params: func
+                .type_params
+                .iter()
+                .map(|(type_param, _)| type_param.clone())
+                .collect(),
+            return_type: func.return_type.clone(),
+            arguments: func
+                .arguments
+                .iter()
+                .map(|argument| TypeCell {
+                    identifier: argument.identifier.clone(),
+                    data_type: argument.data_type.clone(),
+                    mutable: true,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl SyntaxTree {
+    /// Collects the signature of every `func`, so calls can be checked regardless of the order
+    /// functions are declared in. A redeclared function is reported and its first declaration kept,
+    /// except for an executable's extra entry points which [Self::check_entry_point] reports.
+    pub fn build_function_table(
+        &self,
+        target: Target,
+        diagnostics: &mut Diagnostics,
+    ) -> FunctionTable {
+        let mut entry_point_seen = false;
+        let functions = self.items.iter().filter_map(|item| match item {
+            Item::Func(func)
+                if target == Target::Executable
+                    && func.identifier == ENTRY_POINT
+                    && std::mem::replace(&mut entry_point_seen, true) =>
+            {
+                None
+            }
+            Item::Func(func) => Some((func.identifier.as_str(), func.position, func)),
+            _ => None,
+        });
+        diagnostics
+            .first_declarations(functions)
+            .into_iter()
+            .map(FuncSignature::from)
+            .collect()
+    }
+
+    /// An executable needs exactly one entry point, returning `int` or `void`, not generic and
+    /// taking no parameters; a parameter for the command-line arguments can be allowed once there
+    /// are arrays. A library is not run on its own, so it is not checked.
+    pub fn check_entry_point(&self, target: Target, diagnostics: &mut Diagnostics) {
+        if target == Target::Library {
+            return;
+        }
+        let mut entry_points = self.items.iter().filter_map(|item| match item {
+            Item::Func(func) if func.identifier == ENTRY_POINT => Some(func),
+            _ => None,
+        });
+        let Some(entry_point) = entry_points.next() else {
+            diagnostics.report(0, SemanticError::MissingEntryPoint);
+            return;
+        };
+        if !matches!(entry_point.return_type, DataType::Int | DataType::Void)
+            || !entry_point.arguments.is_empty()
+            || !entry_point.type_params.is_empty()
+        {
+            diagnostics.report(entry_point.position, SemanticError::InvalidEntryPoint);
+        }
+        for duplicate in entry_points {
+            diagnostics.report_related(
+                duplicate.position,
+                SemanticError::DuplicateEntryPoint,
+                entry_point.position,
+            );
+        }
+    }
+}
+
+/// Whether a `match` at `position` always runs one of its arms: it has a `default` or `_` arm, or
+/// its cases cover every value and it is among the `exhaustive` matches.
+pub fn match_is_total(
+    cases: &[Case],
+    default: &Option<Block>,
+    position: Position,
+    exhaustive: &[Position],
+) -> bool {
+    default.is_some()
+        || exhaustive.contains(&position)
+        || cases.iter().any(|case| {
+            case.patterns
+                .iter()
+                .any(|pattern| matches!(pattern, Pattern::Wildcard(_)))
+        })
+}
+
+/// Whether control can never reach the end of `block`, leaving it through a `return`, `break` or
+/// `continue` instead.
+///
+/// An `if` jumps when it has an `else` and every branch jumps; a `match` jumps when it is
+/// [total](match_is_total) and every arm jumps. Loops may run zero times and catch the jumps in them, so they
+/// never count.
+pub fn always_jumps(block: &[Stmnt], exhaustive: &[Position]) -> bool {
+    block.iter().any(|stmnt| match stmnt {
+        Stmnt::Return(..) | Stmnt::Break(_) | Stmnt::Continue(_) => true,
+        Stmnt::Block(body, _) => always_jumps(body, exhaustive),
+        Stmnt::If {
+            branches,
+            otherwise: Some(otherwise),
+            ..
+        } => {
+            branches
+                .iter()
+                .all(|(_, body)| always_jumps(body, exhaustive))
+                && always_jumps(otherwise, exhaustive)
+        }
+        Stmnt::Match {
+            cases,
+            default,
+            position,
+            ..
+        } => {
+            match_is_total(cases, default, *position, exhaustive)
+                && cases
+                    .iter()
+                    .all(|case| always_jumps(&case.body, exhaustive))
+                && default
+                    .as_ref()
+                    .is_none_or(|body| always_jumps(body, exhaustive))
+        }
+        _ => false,
+    })
+}
+
+impl TypeChecker<'_> {
+    pub(crate) fn check_return(&mut self, value: &Option<Expr>, position: Position) {
+        let signature = self
+            .current_function
+            .clone()
+            .expect("return outside of a function");
+        let error = match (value, &signature.return_type) {
+            (None, DataType::Void) => None,
+            (None, _) => Some(SemanticError::MissingReturnValue(signature.identifier)),
+            (Some(value), DataType::Void) => {
+                self.expression_type(value);
+                Some(SemanticError::ReturnValueInVoidFunction(
+                    signature.identifier,
+                ))
+            }
+            (Some(value), return_type) => match self.expression_type(value) {
+                Some(value_type)
+                    if !self.is_poisoned(return_type) && !widens_to(&value_type, return_type) =>
+                {
+                    Some(SemanticError::ReturnTypeMismatch(
+                        signature.identifier,
+                        return_type.clone(),
+                        value_type,
+                    ))
+                }
+                _ => None,
+            },
+        };
+        if let Some(error) = error {
+            self.diagnostics.report(position, error);
+        }
+    }
+
+    pub(crate) fn find_function(&self, identifier: &str) -> Option<&FuncSignature> {
+        self.functions
+            .iter()
+            .find(|signature| signature.identifier == identifier)
+    }
+
+    /// Validates a call against the callee's signature and returns the callee's return type.
+    ///
+    /// Arguments are always checked, and a call with wrong arguments still has the callee's
+    /// return type, so the expression around it is checked as usual.
+    pub(crate) fn check_call(
+        &mut self,
+        callee: &Expr,
+        args: &[Expr],
+        position: Position,
+    ) -> Option<DataType> {
+        let arg_types: Vec<Option<DataType>> =
+            args.iter().map(|arg| self.expression_type(arg)).collect();
+        let identifier = match callee {
+            Expr::Identifier(identifier, _) => identifier,
+            _ => {
+                self.diagnostics.report(
+                    position,
+                    SemanticError::InvalidFunctionCall(
+                        callee.root_identifier().unwrap_or_default().to_string(),
+                    ),
+                );
+                return None;
+            }
+        };
+        // Recursion alone does not make a function used.
+        let recursive = self
+            .current_function
+            .as_ref()
+            .is_some_and(|current| current.identifier == *identifier);
+        if !recursive && !self.called.contains(identifier) {
+            self.called.push(identifier.clone());
+        }
+        let signature = match self.find_function(identifier) {
+            Some(signature) => signature.clone(),
+            // A variant carrying data is constructed like a call to a function taking its values.
+            None => match self.find_variant(identifier) {
+                Some((enumeration, variant)) if !variant.parameters().is_empty() => {
+                    let enumeration = DataType::Identifier(enumeration.to_string());
+                    self.mark_type_used(&enumeration);
+                    FuncSignature {
+                        identifier: identifier.clone(),
+                        type_params: Vec::new(),
+                        return_type: enumeration,
+                        arguments: variant.parameters(),
+                    }
+                }
+                _ => {
+                    let error = if self.scopes.find(identifier).is_some()
+                        || self.find_variant(identifier).is_some()
+                    {
+                        SemanticError::InvalidFunctionCall(identifier.clone())
+                    } else {
+                        SemanticError::UndeclaredFunction(identifier.clone())
+                    };
+                    self.diagnostics.report(position, error);
+                    return None;
+                }
+            },
+        };
+        if signature.arguments.len() != args.len() {
+            self.diagnostics.report(
+                position,
+                SemanticError::ArgumentCountMismatch(
+                    identifier.clone(),
+                    signature.arguments.len(),
+                    args.len(),
+                ),
+            );
+            return self.call_type(signature);
+        }
+        let signature = match signature.type_params.is_empty() {
+            true => signature,
+            false => self.instantiate_call(&signature, &arg_types, position)?,
+        };
+        for ((parameter, arg), arg_type) in signature.arguments.iter().zip(args).zip(arg_types) {
+            match arg_type {
+                Some(arg_type)
+                    if !self.is_poisoned(&parameter.data_type)
+                        && !widens_to(&arg_type, &parameter.data_type) =>
+                {
+                    self.diagnostics.report(
+                        arg.position(),
+                        SemanticError::ArgumentTypeMismatch(
+                            identifier.clone(),
+                            parameter.clone(),
+                            arg_type,
+                        ),
+                    )
+                }
+                _ => {}
+            }
+        }
+        self.call_type(signature)
+    }
+
+    /// Type of a call to `signature`, unknown when its return type was never declared.
+    fn call_type(&self, signature: FuncSignature) -> Option<DataType> {
+        (!self.is_poisoned(&signature.return_type)).then_some(signature.return_type)
+    }
+}
@@ -0,0 +1,303 @@
+use crate::front::parser::grammar::DataType;
+use crate::front::parser::syntax_tree::{Block, Expr, FuncDecl, Item, Position, Stmnt, SyntaxTree};
+use crate::front::semantics::expressions::numeric_join;
+use crate::front::semantics::functions::FuncSignature;
+use crate::front::semantics::semantic_errors::SemanticError;
+use crate::front::semantics::types::{IdentifierTypeCell, TypeCell, TypeChecker};
+
+/// Type each type parameter of a generic structure or function stands for.
+pub(crate) type Substitution = Vec<(String, DataType)>;
+
+/// Nesting of generic structures in the type arguments of an instance past which its calls are
+/// taken to instantiate generic functions endlessly, each one calling a bigger instance.
+const MAX_NESTING: usize = 8;
+
+/// `data_type` with every type parameter replaced by its type in `substitution`. Parameters
+/// missing from it are left as they are.
+pub(crate) fn substitute(data_type: &DataType, substitution: &[(String, DataType)]) -> DataType {
+    match data_type {
+        DataType::Parameter(parameter) => substitution
+            .iter()
+            .find(|(name, _)| name == parameter)
+            .map_or_else(|| data_type.clone(), |(_, argument)| argument.clone()),
+        DataType::Applied(identifier, arguments) => DataType::Applied(
+            identifier.clone(),
+            arguments
+                .iter()
+                .map(|argument| substitute(argument, substitution))
+                .collect(),
+        ),
+        data_type => data_type.clone(),
+    }
+}
+
+/// Whether `data_type` still refers to a type parameter.
+pub(crate) fn is_generic(data_type: &DataType) -> bool {
+    match data_type {
+        DataType::Parameter(_) => true,
+        DataType::Applied(_, arguments) => arguments.iter().any(is_generic),
+        _ => false,
+    }
+}
+
+/// Number of generic structures nested in one another in `data_type`.
+fn nesting(data_type: &DataType) -> usize {
+    match data_type {
+        DataType::Applied(_, arguments) => 1 + arguments.iter().map(nesting).max().unwrap_or(0),
+        _ => 0,
+    }
+}
+
+/// Binds the type parameters in `parameter` to the matching parts of the `argument` passed for
+/// it. A parameter bound to several numeric types gets the narrowest one they all widen to, so
+/// `max(1, 2.5)` is `max<double>`; types with no such type are reported once substituted.
+fn infer(parameter: &DataType, argument: &DataType, substitution: &mut Substitution) {
+    match (parameter, argument) {
+        (DataType::Parameter(name), argument) => {
+            match substitution.iter_mut().find(|(bound, _)| bound == name) {
+                Some((_, bound)) => {
+                    if let Some(joined) = numeric_join(bound, argument) {
+                        *bound = joined;
+                    }
+                }
+                None => substitution.push((name.clone(), argument.clone())),
+            }
+        }
+        (DataType::Applied(identifier, parameters), DataType::Applied(applied, arguments))
+            if identifier == applied && parameters.len() == arguments.len() =>
+        {
+            for (parameter, argument) in parameters.iter().zip(arguments) {
+                infer(parameter, argument, substitution);
+            }
+        }
+        _ => {}
+    }
+}
+
+impl FuncDecl {
+    /// Copy of the function for the types in `substitution`, with every type written in it
+    /// substituted, so it is checked like any other function.
+    fn instantiate(&self, substitution: &[(String, DataType)]) -> FuncDecl {
+        let mut instance = self.clone();
+        instance.type_params.clear();
+        instance.return_type = substitute(&self.return_type, substitution);
+        for argument in &mut instance.arguments {
+            argument.data_type = substitute(&argument.data_type, substitution);
+        }
+        substitute_block(&mut instance.body, substitution);
+        instance
+    }
+}
+
+fn substitute_block(block: &mut Block, substitution: &[(String, DataType)]) {
+    for stmnt in block {
+        substitute_stmnt(stmnt, substitution);
+    }
+}
+
+fn substitute_stmnt(stmnt: &mut Stmnt, substitution: &[(String, DataType)]) {
+    match stmnt {
+        Stmnt::Decl(decl) => {
+            if let Some(data_type) = &mut decl.data_type {
+                *data_type = substitute(data_type, substitution);
+            }
+            if let Some(value) = &mut decl.value {
+                substitute_expr(value, substitution);
+            }
+        }
+        Stmnt::Assign(assign) => {
+            substitute_expr(&mut assign.target, substitution);
+            substitute_expr(&mut assign.value, substitution);
+        }
+        Stmnt::Return(value, _) => {
+            if let Some(value) = value {
+                substitute_expr(value, substitution);
+            }
+        }
+        Stmnt::Break(_) | Stmnt::Continue(_) => {}
+        Stmnt::If {
+            branches,
+            otherwise,
+            ..
+        } => {
+            for (condition, body) in branches {
+                substitute_expr(condition, substitution);
+                substitute_block(body, substitution);
+            }
+            if let Some(otherwise) = otherwise {
+                substitute_block(otherwise, substitution);
+            }
+        }
+        Stmnt::Match {
+            scrutinee,
+            cases,
+            default,
+            ..
+        } => {
+            substitute_expr(scrutinee, substitution);
+            for case in cases {
+                substitute_block(&mut case.body, substitution);
+            }
+            if let Some(default) = default {
+                substitute_block(default, substitution);
+            }
+        }
+        Stmnt::For {
+            init,
+            condition,
+            step,
+            body,
+            ..
+        } => {
+            if let Some(data_type) = &mut init.data_type {
+                *data_type = substitute(data_type, substitution);
+            }
+            if let Some(value) = &mut init.value {
+                substitute_expr(value, substitution);
+            }
+            substitute_expr(condition, substitution);
+            substitute_stmnt(step, substitution);
+            substitute_block(body, substitution);
+        }
+        Stmnt::While {
+            condition, body, ..
+        } => {
+            substitute_expr(condition, substitution);
+            substitute_block(body, substitution);
+        }
+        Stmnt::Block(body, _) => substitute_block(body, substitution),
+        Stmnt::Expr(expr, _) => substitute_expr(expr, substitution),
+    }
+}
+
+/// Casts are the only expressions naming a type.
+fn substitute_expr(expr: &mut Expr, substitution: &[(String, DataType)]) {
+    match expr {
+        Expr::Literal(..) | Expr::Identifier(..) => {}
+        Expr::Call { callee, args, .. } => {
+            substitute_expr(callee, substitution);
+            for arg in args {
+                substitute_expr(arg, substitution);
+            }
+        }
+        Expr::Field { base, .. } => substitute_expr(base, substitution),
+        Expr::Index { base, index, .. } => {
+            substitute_expr(base, substitution);
+            substitute_expr(index, substitution);
+        }
+        Expr::Unary { operand, .. } => substitute_expr(operand, substitution),
+        Expr::Cast {
+            operand, data_type, ..
+        } => {
+            substitute_expr(operand, substitution);
+            *data_type = substitute(data_type, substitution);
+        }
+        Expr::Binary { lhs, rhs, .. } => {
+            substitute_expr(lhs, substitution);
+            substitute_expr(rhs, substitution);
+        }
+    }
+}
+
+impl TypeChecker<'_> {
+    /// Type parameters of the structure or enumeration `identifier`, `None` when it is not
+    /// declared. Enumerations are never generic.
+    pub(crate) fn type_params(&self, identifier: &str) -> Option<&[String]> {
+        self.valid_id_types
+            .iter()
+            .find(|valid_t| valid_t.identifier() == identifier)
+            .map(|valid_t| match valid_t {
+                IdentifierTypeCell::StructType { type_params, .. } => type_params.as_slice(),
+                IdentifierTypeCell::EnumType { .. } => &[],
+            })
+    }
+
+    /// Signature of the instance of the generic function `signature` called with arguments of
+    /// `arg_types`, which is recorded to have its body checked. Each type parameter is inferred
+    /// from the arguments; one they do not determine is reported.
+    pub(crate) fn instantiate_call(
+        &mut self,
+        signature: &FuncSignature,
+        arg_types: &[Option<DataType>],
+        position: Position,
+    ) -> Option<FuncSignature> {
+        let mut substitution = Substitution::new();
+        for (parameter, arg_type) in signature.arguments.iter().zip(arg_types) {
+            if let Some(arg_type) = arg_type {
+                infer(&parameter.data_type, arg_type, &mut substitution);
+            }
+        }
+        let mut arguments = Vec::new();
+        for type_param in &signature.type_params {
+            match substitution.iter().find(|(name, _)| name == type_param) {
+                Some((_, argument)) => arguments.push(argument.clone()),
+                // An argument of unknown type was already reported, and may be what was missing.
+                None if arg_types.iter().any(Option::is_none) => return None,
+                None => {
+                    self.diagnostics.report(
+                        position,
+                        SemanticError::CannotInferTypeParameter(
+                            type_param.clone(),
+                            signature.identifier.clone(),
+                        ),
+                    );
+                    return None;
+                }
+            }
+        }
+        if arguments
+            .iter()
+            .any(|argument| nesting(argument) > MAX_NESTING)
+        {
+            self.diagnostics.report(
+                position,
+                SemanticError::RecursiveInstantiation(signature.identifier.clone()),
+            );
+            return None;
+        }
+        // A call from a generic function's own check, with its parameters as arguments, is
+        // checked with the instances of the caller.
+        let instance = (signature.identifier.clone(), arguments);
+        if !instance.1.iter().any(is_generic) && !self.instances.contains(&instance) {
+            self.instances.push(instance);
+        }
+        Some(FuncSignature {
+            identifier: signature.identifier.clone(),
+            type_params: Vec::new(),
+            return_type: substitute(&signature.return_type, &substitution),
+            arguments: signature
+                .arguments
+                .iter()
+                .map(|argument| TypeCell {
+                    identifier: argument.identifier.clone(),
+                    data_type: substitute(&argument.data_type, &substitution),
+                    mutable: argument.mutable,
+                })
+                .collect(),
+        })
+    }
+
+    /// Checks the body of every instance of a generic function, including those only called
+    /// from other instances. Errors found in an instance are reported in the generic function,
+    /// once for every distinct error.
+    pub(crate) fn check_instances(&mut self, tree: &SyntaxTree) {
+        let mut checked = 0;
+        while let Some((identifier, arguments)) = self.instances.get(checked).cloned() {
+            checked += 1;
+            let Some(func) = tree.items.iter().find_map(|item| match item {
+                Item::Func(func) if func.identifier == identifier => Some(func),
+                _ => None,
+            }) else {
+                continue;
+            };
+            let substitution: Substitution = func
+                .type_params
+                .iter()
+                .map(|(type_param, _)| type_param.clone())
+                .zip(arguments)
+                .collect();
+            let instance = func.instantiate(&substitution);
+            self.check_function(&instance, Some(FuncSignature::from(&instance)));
+        }
+    }
+}
@@ -0,0 +1,357 @@
+use crate::front::parser::grammar::DataType;
+use crate::front::parser::syntax_tree::{Item, Position, SyntaxTree};
+use crate::front::semantics::diagnostics::Diagnostics;
+use crate::front::semantics::generics::{substitute, Substitution};
+use crate::front::semantics::semantic_errors::SemanticError;
+use crate::front::semantics::types::{IdentifierTypeCell, IdentifierTypeTable};
+use std::fmt;
+
+/// How a structure or enumeration is laid out in memory, in bytes.
+#[derive(Debug, Clone)]
+pub struct Layout {
+    pub identifier: String,
+    pub size: usize,
+    pub align: usize,
+    /// Each field with its type and offset from the start of the structure, in declaration
+    /// order. The payload of an enumeration's variants is listed as `Variant.field`.
+    pub fields: Vec<(String, DataType, usize)>,
+    pub is_enum: bool,
+}
+
+impl fmt::Display for Layout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = if self.is_enum {
+            "enumeration"
+        } else {
+            "struct"
+        };
+        write!(
+            f,
+            "{kind} {}: size {}, align {}",
+            self.identifier, self.size, self.align
+        )?;
+        for (field, data_type, offset) in &self.fields {
+            write!(f, "\n  {field}: {data_type} at offset {offset}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Rounds `offset` up to the next multiple of `align`.
+fn align_to(offset: usize, align: usize) -> usize {
+    offset.div_ceil(align) * align
+}
+
+/// Size and alignment of a builtin type. A `string` is a pointer and a length.
+fn builtin_layout(data_type: &DataType) -> Option<(usize, usize)> {
+    match data_type {
+        DataType::Int | DataType::Float | DataType::Char => Some((4, 4)),
+        DataType::Long | DataType::Double => Some((8, 8)),
+        DataType::Bool => Some((1, 1)),
+        DataType::Str => Some((16, 8)),
+        DataType::Void => Some((0, 1)),
+        DataType::Identifier(_) | DataType::Parameter(_) | DataType::Applied(..) => None,
+    }
+}
+
+/// Values of `identifier` stored inline because their type is a structure or enumeration: the
+/// fields of a structure, or the payload of each variant of an enumeration labelled
+/// `Variant.field`, tuple values being numbered from 1.
+fn inline_fields(types: &IdentifierTypeTable, identifier: &str) -> Vec<(String, String)> {
+    let fields: Vec<(String, &DataType)> = match types
+        .iter()
+        .find(|valid_t| valid_t.identifier() == identifier)
+    {
+        Some(IdentifierTypeCell::StructType { fields, .. }) => fields
+            .iter()
+            .map(|field| (field.identifier.clone(), &field.data_type))
+            .collect(),
+        Some(IdentifierTypeCell::EnumType { variants, .. }) => variants
+            .iter()
+            .flat_map(|variant| {
+                variant
+                    .payload
+                    .data_types()
+                    .into_iter()
+                    .zip(variant.parameters())
+                    .map(|(data_type, parameter)| {
+                        (
+                            format!("{}.{}", variant.identifier, parameter.identifier),
+                            data_type,
+                        )
+                    })
+            })
+            .collect(),
+        None => Vec::new(),
+    };
+    fields
+        .into_iter()
+        .flat_map(|(field, data_type)| {
+            inline_types(data_type)
+                .into_iter()
+                .filter(|field_type| {
+                    types
+                        .iter()
+                        .any(|valid_t| valid_t.identifier() == field_type)
+                })
+                .map(move |field_type| (field.clone(), field_type))
+        })
+        .collect()
+}
+
+/// Structures and enumerations a value of `data_type` stores. Fields are stored by value, so a
+/// generic structure is taken to store its type arguments too.
+fn inline_types(data_type: &DataType) -> Vec<String> {
+    match data_type {
+        DataType::Identifier(identifier) => vec![identifier.clone()],
+        DataType::Applied(identifier, arguments) => [identifier.clone()]
+            .into_iter()
+            .chain(arguments.iter().flat_map(inline_types))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Path of fields leading from `identifier` back to `target`, if there is one.
+fn cycle_path(
+    types: &IdentifierTypeTable,
+    identifier: &str,
+    target: &str,
+    visited: &mut Vec<String>,
+) -> Option<Vec<(String, String)>> {
+    visited.push(identifier.to_string());
+    for (field, field_type) in inline_fields(types, identifier) {
+        if field_type == target {
+            return Some(vec![(identifier.to_string(), field)]);
+        }
+        if visited.contains(&field_type) {
+            continue;
+        }
+        if let Some(mut path) = cycle_path(types, &field_type, target, visited) {
+            path.insert(0, (identifier.to_string(), field));
+            return Some(path);
+        }
+    }
+    None
+}
+
+impl SyntaxTree {
+    /// Reports every structure or enumeration that contains itself by value, directly or through
+    /// other types, since it would need infinite space. Each cycle is reported once, at the field
+    /// or variant of its first type, and the types on a cycle are returned.
+    pub fn check_recursive_structs(
+        &self,
+        types: &IdentifierTypeTable,
+        diagnostics: &mut Diagnostics,
+    ) -> Vec<String> {
+        let mut recursive: Vec<String> = Vec::new();
+        for valid_t in types {
+            let identifier = valid_t.identifier();
+            if recursive.iter().any(|found| found == identifier) {
+                continue;
+            }
+            let Some(path) = cycle_path(types, identifier, identifier, &mut Vec::new()) else {
+                continue;
+            };
+            let cycle = path
+                .iter()
+                .map(|(structure, field)| format!("{structure}.{field}"))
+                .chain([identifier.to_string()])
+                .collect::<Vec<_>>()
+                .join(" -> ");
+            let kind = match valid_t {
+                IdentifierTypeCell::StructType { .. } => "struct",
+                IdentifierTypeCell::EnumType { .. } => "enumeration",
+            };
+            diagnostics.report(
+                self.field_position(identifier, &path[0].1),
+                SemanticError::RecursiveStruct(kind, identifier.to_string(), cycle),
+            );
+            recursive.extend(path.into_iter().map(|(structure, _)| structure));
+        }
+        recursive
+    }
+
+    /// Where `field` of a structure, or the variant of a `Variant.field` label, is declared.
+    fn field_position(&self, identifier: &str, field: &str) -> Position {
+        self.items
+            .iter()
+            .find_map(|item| match item {
+                Item::Struct(declaration) if declaration.identifier == identifier => declaration
+                    .fields
+                    .iter()
+                    .find(|declared| declared.identifier == field)
+                    .map(|declared| declared.position),
+                Item::Enum(declaration) if declaration.identifier == identifier => {
+                    let variant = field.split('.').next().unwrap_or_default();
+                    declaration
+                        .variants
+                        .iter()
+                        .find(|declared| declared.identifier == variant)
+                        .map(|declared| declared.position)
+                }
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// Lays out every structure and enumeration in declaration order. Structures store their fields
+/// in order, each at the next offset suited to its alignment, and are padded to a multiple of
+/// their alignment. Enumerations store the smallest integer holding their discriminants, followed
+/// by the largest payload of their variants, each laid out like a structure. Types that are
+/// `recursive` or use an undeclared type have no size; both are reported as errors, so no layout
+/// is given for them.
+///
+/// Generic structures have no layout of their own, only one for each of their `instantiated`
+/// types, such as `Pair<int>`, which come after the other types.
+pub fn compute_layouts(
+    types: &IdentifierTypeTable,
+    recursive: &[String],
+    instantiated: &[DataType],
+) -> Vec<Layout> {
+    let mut layouts = Vec::new();
+    for valid_t in types {
+        layout_of(types, recursive, valid_t.identifier(), &[], &mut layouts);
+    }
+    for data_type in instantiated {
+        type_layout(types, recursive, data_type, &mut layouts);
+    }
+    let instances = layouts
+        .iter()
+        .filter(|layout| layout.identifier.contains('<'))
+        .cloned();
+    types
+        .iter()
+        .filter_map(|valid_t| {
+            layouts
+                .iter()
+                .find(|layout| layout.identifier == valid_t.identifier())
+                .cloned()
+        })
+        .chain(instances)
+        .collect()
+}
+
+/// Size and alignment of `data_type`, laying out the types it depends on first.
+fn type_layout(
+    types: &IdentifierTypeTable,
+    recursive: &[String],
+    data_type: &DataType,
+    layouts: &mut Vec<Layout>,
+) -> Option<(usize, usize)> {
+    match data_type {
+        DataType::Identifier(identifier) => layout_of(types, recursive, identifier, &[], layouts),
+        DataType::Applied(identifier, arguments) => {
+            layout_of(types, recursive, identifier, arguments, layouts)
+        }
+        builtin => builtin_layout(builtin),
+    }
+}
+
+/// Size of the smallest integer holding every discriminant, unsigned unless one is negative.
+fn tag_size(discriminants: impl Iterator<Item = i64>) -> usize {
+    let (min, max) = discriminants.fold((0, 0), |(min, max), discriminant| {
+        (min.min(discriminant), max.max(discriminant))
+    });
+    let fits = |bits: u32| match min < 0 {
+        true => min >= -(1 << (bits - 1)) && max < 1 << (bits - 1),
+        false => max < 1 << bits,
+    };
+    [1, 2, 4]
+        .into_iter()
+        .find(|bytes| fits(*bytes as u32 * 8))
+        .unwrap_or(8)
+}
+
+/// Layout of the structure or enumeration `identifier` given type `arguments`, which must be as
+/// many as its type parameters.
+fn layout_of(
+    types: &IdentifierTypeTable,
+    recursive: &[String],
+    identifier: &str,
+    arguments: &[DataType],
+    layouts: &mut Vec<Layout>,
+) -> Option<(usize, usize)> {
+    let name = match arguments.is_empty() {
+        true => identifier.to_string(),
+        false => DataType::Applied(identifier.to_string(), arguments.to_vec()).to_string(),
+    };
+    if let Some(layout) = layouts.iter().find(|layout| layout.identifier == name) {
+        return Some((layout.size, layout.align));
+    }
+    if recursive.iter().any(|structure| structure == identifier) {
+        return None;
+    }
+    let layout = match types
+        .iter()
+        .find(|valid_t| valid_t.identifier() == identifier)?
+    {
+        IdentifierTypeCell::StructType {
+            type_params,
+            fields: cells,
+            ..
+        } => {
+            if type_params.len() != arguments.len() {
+                return None;
+            }
+            let substitution: Substitution = type_params
+                .iter()
+                .cloned()
+                .zip(arguments.to_vec())
+                .collect();
+            let mut fields = Vec::new();
+            let (mut size, mut align) = (0, 1);
+            for cell in cells {
+                let data_type = substitute(&cell.data_type, &substitution);
+                let (field_size, field_align) = type_layout(types, recursive, &data_type, layouts)?;
+                let offset = align_to(size, field_align);
+                fields.push((cell.identifier.clone(), data_type, offset));
+                size = offset + field_size;
+                align = align.max(field_align);
+            }
+            Layout {
+                identifier: name,
+                size: align_to(size, align),
+                align,
+                fields,
+                is_enum: false,
+            }
+        }
+        IdentifierTypeCell::EnumType { .. } if !arguments.is_empty() => return None,
+        IdentifierTypeCell::EnumType { variants, .. } => {
+            let tag = tag_size(variants.iter().map(|variant| variant.discriminant));
+            let mut fields = Vec::new();
+            let (mut size, mut align) = (tag, tag);
+            // Every payload starts after the tag and shares the same space.
+            for variant in variants {
+                let mut end = tag;
+                for (data_type, parameter) in variant
+                    .payload
+                    .data_types()
+                    .into_iter()
+                    .zip(variant.parameters())
+                {
+                    let (field_size, field_align) =
+                        type_layout(types, recursive, data_type, layouts)?;
+                    let offset = align_to(end, field_align);
+                    let label = format!("{}.{}", variant.identifier, parameter.identifier);
+                    fields.push((label, data_type.clone(), offset));
+                    end = offset + field_size;
+                    align = align.max(field_align);
+                }
+                size = size.max(end);
+            }
+            Layout {
+                identifier: identifier.to_string(),
+                size: align_to(size, align),
+                align,
+                fields,
+                is_enum: true,
+            }
+        }
+    };
+    let size_align = (layout.size, layout.align);
+    layouts.push(layout);
+    Some(size_align)
+}
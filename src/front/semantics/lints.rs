@@ -0,0 +1,167 @@
+use crate::front::semantics::semantic_errors::{SemanticError, SemanticWarning};
+use std::fmt;
+use std::str::FromStr;
+
+/// How a diagnostic is reported: not at all, as a warning, or as an error failing compilation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Level {
+    Allow,
+    Warn,
+    Deny,
+}
+
+impl FromStr for Level {
+    type Err = ();
+
+    /// Names used by the `allow`, `warn` and `deny` function attributes.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "allow" => Ok(Level::Allow),
+            "warn" => Ok(Level::Warn),
+            "deny" => Ok(Level::Deny),
+            _ => Err(()),
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Level::Allow => write!(f, "allow"),
+            Level::Warn => write!(f, "warn"),
+            Level::Deny => write!(f, "deny"),
+        }
+    }
+}
+
+/// Codes of every warning, whose level can be changed.
+pub const LINT_CODES: &[&str] = &[
+    "G0101", "G0102", "G0103", "G0104", "G0105", "G0106", "G0107",
+];
+
+impl SemanticWarning {
+    /// Stable identifier of the warning, used to change its level.
+    pub fn code(&self) -> &'static str {
+        match self {
+            SemanticWarning::UnusedVariable(_) => "G0101",
+            SemanticWarning::UnusedParameter(_) => "G0102",
+            SemanticWarning::UnusedFunction(_) => "G0103",
+            SemanticWarning::UnusedType(_) => "G0104",
+            SemanticWarning::UnreachableDefault(_) => "G0105",
+            SemanticWarning::UnreachableCode(_) => "G0106",
+            SemanticWarning::ConstantCondition(_) => "G0107",
+        }
+    }
+
+    pub fn default_level(&self) -> Level {
+        Level::Warn
+    }
+}
+
+impl SemanticError {
+    /// Stable identifier of the error. Errors are always denied.
+    pub fn code(&self) -> &'static str {
+        match self {
+            SemanticError::InvalidType => "G0001",
+            SemanticError::TypeMismatch => "G0002",
+            SemanticError::UndefinedVariable(..) => "G0003",
+            SemanticError::DivisionByZero => "G0005",
+            SemanticError::MissingIdentifier => "G0006",
+            SemanticError::MissingDataType => "G0007",
+            SemanticError::UndeclaredType(..) => "G0008",
+            SemanticError::UndeclaredIdentifier(..) => "G0009",
+            SemanticError::IncompatibleTypes(..) => "G0010",
+            SemanticError::InvalidExpressionWithVariant(..) => "G0011",
+            SemanticError::InvalidStructUsage(..) => "G0012",
+            SemanticError::UnexpectedSymbolInExpression(..) => "G0013",
+            SemanticError::InvalidEnumVariant(..) => "G0014",
+            SemanticError::RedeclaredIdentifier(..) => "G0015",
+            SemanticError::VariantNotDeclared(..) => "G0016",
+            SemanticError::NonConstantExpression(..) => "G0017",
+            SemanticError::AssignmentToConstant(..) => "G0018",
+            SemanticError::PatternTypeMismatch(..) => "G0019",
+            SemanticError::EmptyRange(..) => "G0020",
+            SemanticError::DuplicatePattern(..) => "G0021",
+            SemanticError::UnreachablePattern(..) => "G0022",
+            SemanticError::NonExhaustiveMatch(..) => "G0023",
+            SemanticError::UndeclaredFunction(..) => "G0024",
+            SemanticError::InvalidFunctionCall(..) => "G0025",
+            SemanticError::ArgumentCountMismatch(..) => "G0026",
+            SemanticError::ArgumentTypeMismatch(..) => "G0027",
+            SemanticError::InvalidStatement(..) => "G0028",
+            SemanticError::MissingReturnValue(..) => "G0029",
+            SemanticError::ReturnValueInVoidFunction(..) => "G0030",
+            SemanticError::ReturnTypeMismatch(..) => "G0031",
+            SemanticError::NotAllPathsReturn(..) => "G0032",
+            SemanticError::InvalidAssignmentTarget(..) => "G0033",
+            SemanticError::UnsupportedOperation(..) => "G0034",
+            SemanticError::InvalidOperands(..) => "G0035",
+            SemanticError::InvalidUnaryOperand(..) => "G0036",
+            SemanticError::InvalidFieldAccess(..) => "G0037",
+            SemanticError::UndeclaredField(..) => "G0038",
+            SemanticError::InvalidCast(..) => "G0039",
+            SemanticError::NonBooleanCondition(..) => "G0040",
+            SemanticError::IntegerCondition(..) => "G0041",
+            SemanticError::AssignmentInCondition => "G0042",
+            SemanticError::AssignmentInExpression => "G0043",
+            SemanticError::VoidInitialiser(..) => "G0044",
+            SemanticError::MissingInitialiser(..) => "G0045",
+            SemanticError::UnassignedVariable(..) => "G0046",
+            SemanticError::UsedBeforeDeclaration(..) => "G0047",
+            SemanticError::UnknownAttribute(..) => "G0048",
+            SemanticError::UnknownLint(..) => "G0049",
+            SemanticError::JumpOutsideLoop(..) => "G0050",
+            SemanticError::ConstantOverflow(..) => "G0051",
+            SemanticError::ConstantOutOfRange(..) => "G0052",
+            SemanticError::MissingEntryPoint => "G0053",
+            SemanticError::InvalidEntryPoint => "G0054",
+            SemanticError::DuplicateEntryPoint => "G0055",
+            SemanticError::RecursiveStruct(..) => "G0056",
+            SemanticError::InvalidDiscriminant(..) => "G0057",
+            SemanticError::DuplicateDiscriminant(..) => "G0058",
+            SemanticError::PatternBindingCount(..) => "G0059",
+            SemanticError::AlternativeBindings(..) => "G0060",
+            SemanticError::VariantNeedsPayload(..) => "G0061",
+            SemanticError::TypeArgumentCount(..) => "G0062",
+            SemanticError::CannotInferTypeParameter(..) => "G0063",
+            SemanticError::RecursiveInstantiation(..) => "G0064",
+        }
+    }
+}
+
+/// Levels chosen from the command line, applied to every function that does not override them.
+#[derive(Debug, Default, Clone)]
+pub struct LintLevels {
+    overrides: Vec<(String, Level)>,
+    /// Turns every warning into an error, whatever level it was given.
+    pub deny_warnings: bool,
+}
+
+impl LintLevels {
+    /// Sets the level of the lint `code`, a later call for the same code winning.
+    pub fn set(&mut self, code: &str, level: Level) -> Result<(), String> {
+        if !LINT_CODES.contains(&code) {
+            return Err(format!("unknown lint '{code}'"));
+        }
+        self.overrides.push((code.to_string(), level));
+        Ok(())
+    }
+
+    /// Level `warning` is reported at, `source` being the level set by an attribute on the
+    /// enclosing function, which takes precedence over the command line.
+    pub fn level(&self, warning: &SemanticWarning, source: Option<Level>) -> Level {
+        let level = source
+            .or_else(|| {
+                self.overrides
+                    .iter()
+                    .rev()
+                    .find(|(code, _)| code == warning.code())
+                    .map(|(_, level)| *level)
+            })
+            .unwrap_or(warning.default_level());
+        match level {
+            Level::Warn if self.deny_warnings => Level::Deny,
+            level => level,
+        }
+    }
+}
@@ -1,2 +1,14 @@
-pub mod types;
+pub mod cfg;
+pub mod diagnostics;
+pub mod expressions;
+pub mod flow;
+pub mod fold;
+pub mod functions;
+pub mod generics;
+pub mod layout;
+pub mod lints;
+pub mod patterns;
+pub mod scope;
 pub mod semantic_errors;
+pub mod suggestions;
+pub mod types;
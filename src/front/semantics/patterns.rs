@@ -0,0 +1,339 @@
+use crate::front::lexer::reserved::Separator;
+use crate::front::lexer::tokens::{Bool, Literal};
+use crate::front::parser::grammar::DataType;
+use crate::front::parser::syntax_tree::{
+    Block, Case, Expr, Pattern, Position, VariantPayload, WILDCARD,
+};
+use crate::front::semantics::expressions::widens_to;
+use crate::front::semantics::flow::Join;
+use crate::front::semantics::semantic_errors::{SemanticError, SemanticWarning};
+use crate::front::semantics::suggestions::closest_name;
+use crate::front::semantics::types::{
+    unknown_type, IdentifierTypeCell, TypeCell, TypeChecker, VariantCell,
+};
+
+impl TypeChecker<'_> {
+    pub(crate) fn check_match(
+        &mut self,
+        scrutinee: &Expr,
+        cases: &[Case],
+        default: &Option<Block>,
+        position: Position,
+    ) {
+        let scrutinee_type = self.expression_type(scrutinee);
+        let before = self.scopes.assignments();
+        let mut join = Join::default();
+        let mut seen: Vec<&Pattern> = Vec::new();
+        for case in cases {
+            let mut bindings = Vec::new();
+            for pattern in &case.patterns {
+                // Any of the patterns may have matched, so none of them can bind values.
+                let alternatives = case.patterns.len() > 1;
+                if alternatives
+                    && matches!(pattern, Pattern::Variant { bindings, .. } if !bindings.is_empty())
+                {
+                    self.diagnostics.report(
+                        pattern.position(),
+                        SemanticError::AlternativeBindings(pattern.to_string()),
+                    );
+                }
+                // A rejected pattern still binds its names and counts towards the values
+                // covered, so the mistake is reported once.
+                let pattern_bindings = match &scrutinee_type {
+                    Some(data_type) => self
+                        .validate_pattern(scrutinee, pattern, data_type)
+                        .unwrap_or_else(|error| {
+                            self.diagnostics.report(pattern.position(), *error);
+                            unknown_bindings(pattern)
+                        }),
+                    None => unknown_bindings(pattern),
+                };
+                if !alternatives {
+                    bindings.extend(pattern_bindings);
+                }
+                if let Some(earlier) = seen.iter().find(|earlier| covers(earlier, pattern)) {
+                    let error = if covers(pattern, earlier) {
+                        SemanticError::DuplicatePattern(pattern.to_string())
+                    } else {
+                        SemanticError::UnreachablePattern(pattern.to_string())
+                    };
+                    self.diagnostics.report(pattern.position(), error);
+                    continue;
+                }
+                seen.push(pattern);
+            }
+            self.check_case_branch(&case.body, bindings, &before, &mut join);
+        }
+        let exhaustive = match &scrutinee_type {
+            Some(data_type) => {
+                self.check_exhaustiveness(scrutinee, &seen, data_type, default.is_some(), position)
+            }
+            None => false,
+        };
+        if exhaustive {
+            self.exhaustive_matches.push(position);
+        }
+        match default {
+            Some(body) => self.check_branch(body, &before, &mut join),
+            // Values no case matches skip the match entirely.
+            None if !exhaustive => join.path(before.clone()),
+            None => {}
+        }
+        self.scopes.restore_assignments(&join.finish(before));
+    }
+
+    /// Enumerations and booleans have a closed set of values, so a match over them must either
+    /// cover every value or have a `default` arm, and a `default` after full coverage is dead.
+    ///
+    /// Returns whether the cases alone match every value.
+    fn check_exhaustiveness(
+        &mut self,
+        scrutinee: &Expr,
+        patterns: &[&Pattern],
+        data_type: &DataType,
+        has_default: bool,
+        position: Position,
+    ) -> bool {
+        let wildcard = patterns
+            .iter()
+            .any(|pattern| matches!(pattern, Pattern::Wildcard(_)));
+        let values: Vec<String> = match data_type {
+            DataType::Bool => vec![
+                Literal::Bool(Bool::True).to_string(),
+                Literal::Bool(Bool::False).to_string(),
+            ],
+            DataType::Identifier(enumeration) => match self.enum_variants(enumeration) {
+                Some(variants) => variants
+                    .iter()
+                    .map(|variant| variant.identifier.clone())
+                    .collect(),
+                None => return wildcard,
+            },
+            _ => return wildcard,
+        };
+        let missing: Vec<String> = values
+            .into_iter()
+            .filter(|value| {
+                !wildcard
+                    && !patterns.iter().any(|pattern| match pattern {
+                        Pattern::Literal(literal, _) => literal.to_string() == *value,
+                        Pattern::Variant { variant, .. } => variant == value,
+                        _ => false,
+                    })
+            })
+            .collect();
+        let exhaustive = missing.is_empty();
+        match (exhaustive, has_default) {
+            (false, false) => self
+                .diagnostics
+                .report(position, SemanticError::NonExhaustiveMatch(missing)),
+            (true, true) => self.warn(
+                position,
+                SemanticWarning::UnreachableDefault(
+                    scrutinee.root_identifier().unwrap_or_default().to_string(),
+                ),
+            ),
+            _ => {}
+        }
+        exhaustive
+    }
+
+    /// Checks that `pattern` can match a value of `data_type`, returning the variables it binds
+    /// with where each is bound.
+    fn validate_pattern(
+        &self,
+        scrutinee: &Expr,
+        pattern: &Pattern,
+        data_type: &DataType,
+    ) -> Result<Vec<(TypeCell, Position)>, Box<SemanticError>> {
+        let mismatch = || {
+            Err(Box::from(SemanticError::PatternTypeMismatch(
+                pattern.to_string(),
+                data_type.clone(),
+            )))
+        };
+        match pattern {
+            Pattern::Wildcard(_) => Ok(Vec::new()),
+            Pattern::Literal(literal, _) => match DataType::from(literal.to_owned()) {
+                literal_type if widens_to(&literal_type, data_type) => Ok(Vec::new()),
+                _ => mismatch(),
+            },
+            Pattern::Range(start, end, _) => {
+                if !matches!(data_type, DataType::Int | DataType::Long | DataType::Char)
+                    || !widens_to(&DataType::from(start.to_owned()), data_type)
+                    || !widens_to(&DataType::from(end.to_owned()), data_type)
+                {
+                    return mismatch();
+                }
+                if start.ordinal() > end.ordinal() {
+                    return Err(Box::from(SemanticError::EmptyRange(pattern.to_string())));
+                }
+                Ok(Vec::new())
+            }
+            Pattern::Variant {
+                enumeration,
+                variant,
+                bindings,
+                ..
+            } => {
+                let enum_type = self.valid_id_types.iter().find(|valid_t| match valid_t {
+                    IdentifierTypeCell::EnumType { identifier, .. } => {
+                        *data_type == DataType::Identifier(identifier.clone())
+                            && enumeration
+                                .as_ref()
+                                .is_none_or(|enumeration| enumeration == identifier)
+                    }
+                    _ => false,
+                });
+                let declared = match enum_type {
+                    Some(IdentifierTypeCell::EnumType { variants, .. }) => variants
+                        .iter()
+                        .find(|declared| declared.identifier == *variant),
+                    _ => None,
+                };
+                match (enum_type, declared) {
+                    (Some(enum_type), Some(declared)) => {
+                        bind_payload(pattern, enum_type.identifier(), declared, bindings)
+                    }
+                    (Some(enum_type), None) => Err(Box::from(SemanticError::VariantNotDeclared(
+                        TypeCell {
+                            identifier: scrutinee.root_identifier().unwrap_or_default().to_string(),
+                            data_type: data_type.clone(),
+                            mutable: false,
+                        },
+                        variant.clone(),
+                        enum_type.clone(),
+                    ))),
+                    (None, _) => mismatch(),
+                }
+            }
+        }
+    }
+}
+
+/// Variables `pattern` binds, of a type that is not known, for a pattern whose payload could not
+/// be checked.
+fn unknown_bindings(pattern: &Pattern) -> Vec<(TypeCell, Position)> {
+    match pattern {
+        Pattern::Variant { bindings, .. } => bindings
+            .iter()
+            .filter(|(binding, _)| binding != WILDCARD)
+            .map(|(binding, position)| {
+                let cell = TypeCell {
+                    identifier: binding.clone(),
+                    data_type: unknown_type(),
+                    mutable: false,
+                };
+                (cell, *position)
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Variables bound to the payload of `variant`. A tuple variant binds each of its values in
+/// order, and a variant with fields binds the fields named; `_` leaves a value unbound. A
+/// variant written without bindings matches whatever it carries.
+fn bind_payload(
+    pattern: &Pattern,
+    enumeration: &str,
+    variant: &VariantCell,
+    bindings: &[(String, Position)],
+) -> Result<Vec<(TypeCell, Position)>, Box<SemanticError>> {
+    let parameters = variant.parameters();
+    let bound = |parameter: &TypeCell, binding: &String, position: &Position| {
+        let cell = TypeCell {
+            identifier: binding.clone(),
+            data_type: parameter.data_type.clone(),
+            mutable: false,
+        };
+        (cell, *position)
+    };
+    if !matches!(variant.payload, VariantPayload::Struct(_)) {
+        if !bindings.is_empty() && bindings.len() != parameters.len() {
+            return Err(Box::from(SemanticError::PatternBindingCount(
+                pattern.to_string(),
+                parameters.len(),
+                bindings.len(),
+            )));
+        }
+        return Ok(parameters
+            .iter()
+            .zip(bindings)
+            .filter(|(_, (binding, _))| binding != WILDCARD)
+            .map(|(parameter, (binding, position))| bound(parameter, binding, position))
+            .collect());
+    }
+    let mut cells = Vec::new();
+    for (binding, position) in bindings.iter().filter(|(binding, _)| binding != WILDCARD) {
+        match parameters
+            .iter()
+            .find(|parameter| parameter.identifier == *binding)
+        {
+            Some(parameter) => cells.push(bound(parameter, binding, position)),
+            None => {
+                let fields = parameters
+                    .iter()
+                    .map(|parameter| parameter.identifier.as_str());
+                return Err(Box::from(SemanticError::UndeclaredField(
+                    format!(
+                        "{enumeration}{}{}",
+                        Separator::DoubleColon,
+                        variant.identifier
+                    ),
+                    binding.clone(),
+                    closest_name(binding, fields),
+                )));
+            }
+        }
+    }
+    Ok(cells)
+}
+
+/// Whether two literals stand for the same value, whatever their types. Integers are compared
+/// exactly, as not every `long` is a `double`.
+fn same_value(earlier: &Literal, later: &Literal) -> bool {
+    match (earlier.ordinal(), later.ordinal()) {
+        (Some(earlier), Some(later)) => earlier == later,
+        _ => match (earlier.numeric_value(), later.numeric_value()) {
+            (Some(earlier), Some(later)) => earlier == later,
+            _ => earlier == later,
+        },
+    }
+}
+
+/// Whether every value matched by `later` is already matched by `earlier`.
+fn covers(earlier: &Pattern, later: &Pattern) -> bool {
+    match (earlier, later) {
+        (Pattern::Wildcard(_), _) => true,
+        (_, Pattern::Wildcard(_)) => false,
+        (Pattern::Literal(earlier, _), Pattern::Literal(later, _)) => same_value(earlier, later),
+        (Pattern::Range(start, end, _), Pattern::Literal(literal, _)) => {
+            match (start.ordinal(), end.ordinal(), literal.ordinal()) {
+                (Some(start), Some(end), Some(literal)) => start <= literal && literal <= end,
+                _ => false,
+            }
+        }
+        (Pattern::Range(start, end, _), Pattern::Range(later_start, later_end, _)) => match (
+            start.ordinal(),
+            end.ordinal(),
+            later_start.ordinal(),
+            later_end.ordinal(),
+        ) {
+            (Some(start), Some(end), Some(later_start), Some(later_end)) => {
+                start <= later_start && later_end <= end
+            }
+            _ => false,
+        },
+        (Pattern::Literal(literal, _), Pattern::Range(start, end, _)) => {
+            literal.ordinal().is_some() && same_value(start, literal) && same_value(end, literal)
+        }
+        (
+            Pattern::Variant {
+                variant: earlier, ..
+            },
+            Pattern::Variant { variant: later, .. },
+        ) => earlier == later,
+        _ => false,
+    }
+}
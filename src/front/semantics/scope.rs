@@ -0,0 +1,142 @@
+use crate::front::parser::syntax_tree::Position;
+use crate::front::semantics::types::TypeCell;
+
+/// Binding of a name in one block.
+#[derive(Debug)]
+struct Binding {
+    cell: TypeCell,
+    position: Position,
+    /// Whether the binding has been read since it was declared.
+    used: bool,
+    /// Whether a value is stored in the binding on every path reaching the current statement.
+    assigned: bool,
+}
+
+type Scope = Vec<Binding>;
+
+/// Symbol table with one [`Scope`] per open block.
+///
+/// Shadowing rules:
+/// - a name may be declared only once per block, function arguments sharing the block of the
+///   function body;
+/// - an inner block may shadow a name declared by any enclosing block, the outer binding being
+///   visible again once the inner block closes;
+/// - each function starts from an empty stack, so functions never see each other's variables.
+#[derive(Debug, Default)]
+pub struct ScopedTypeTable {
+    scopes: Vec<Scope>,
+}
+
+impl ScopedTypeTable {
+    pub fn enter_scope(&mut self) {
+        self.scopes.push(Scope::new());
+    }
+
+    /// Closes the innermost block, returning the bindings declared in it that were never read,
+    /// with where they were declared.
+    pub fn exit_scope(&mut self) -> Vec<(TypeCell, Position)> {
+        self.scopes
+            .pop()
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|binding| (!binding.used).then_some((binding.cell, binding.position)))
+            .collect()
+    }
+
+    /// Binds `cell` in the innermost block. Fails with the position of the earlier declaration
+    /// when the block already binds the name.
+    pub fn declare(
+        &mut self,
+        cell: TypeCell,
+        position: Position,
+        assigned: bool,
+    ) -> Result<(), Position> {
+        let scope = self
+            .scopes
+            .last_mut()
+            .expect("declaration outside of any scope");
+        if let Some(declared) = scope
+            .iter()
+            .find(|binding| binding.cell.identifier == cell.identifier)
+        {
+            return Err(declared.position);
+        }
+        scope.push(Binding {
+            cell,
+            position,
+            used: false,
+            assigned,
+        });
+        Ok(())
+    }
+
+    /// Names of every binding visible from the current block, innermost first.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.scopes
+            .iter()
+            .rev()
+            .flat_map(|scope| scope.iter().rev())
+            .map(|binding| binding.cell.identifier.as_str())
+    }
+
+    /// Innermost binding of `identifier` visible from the current block.
+    pub fn find(&self, identifier: &str) -> Option<&TypeCell> {
+        self.scopes.iter().rev().find_map(|scope| {
+            scope
+                .iter()
+                .find(|binding| binding.cell.identifier == identifier)
+                .map(|binding| &binding.cell)
+        })
+    }
+
+    fn find_binding_mut(&mut self, identifier: &str) -> Option<&mut Binding> {
+        self.scopes.iter_mut().rev().find_map(|scope| {
+            scope
+                .iter_mut()
+                .find(|binding| binding.cell.identifier == identifier)
+        })
+    }
+
+    /// Records a read of the innermost binding of `identifier`.
+    pub fn mark_used(&mut self, identifier: &str) {
+        if let Some(binding) = self.find_binding_mut(identifier) {
+            binding.used = true;
+        }
+    }
+
+    /// Whether the innermost binding of `identifier` definitely holds a value.
+    pub fn is_assigned(&self, identifier: &str) -> bool {
+        self.scopes.iter().rev().find_map(|scope| {
+            scope
+                .iter()
+                .find(|binding| binding.cell.identifier == identifier)
+                .map(|binding| binding.assigned)
+        }) == Some(true)
+    }
+
+    /// Records a value being stored in the innermost binding of `identifier`.
+    pub fn mark_assigned(&mut self, identifier: &str) {
+        if let Some(binding) = self.find_binding_mut(identifier) {
+            binding.assigned = true;
+        }
+    }
+
+    /// Whether each visible binding definitely holds a value, outermost first, so the state can
+    /// be saved before a branch and restored after it.
+    pub fn assignments(&self) -> Vec<bool> {
+        self.scopes
+            .iter()
+            .flatten()
+            .map(|binding| binding.assigned)
+            .collect()
+    }
+
+    /// Restores a state saved by [`Self::assignments`] with the same blocks open.
+    pub fn restore_assignments(&mut self, assignments: &[bool]) {
+        self.scopes
+            .iter_mut()
+            .flatten()
+            .zip(assignments)
+            .for_each(|(binding, assigned)| binding.assigned = *assigned);
+    }
+}
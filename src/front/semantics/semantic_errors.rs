@@ -1,24 +1,19 @@
+use crate::front::parser::grammar::DataType;
 use crate::front::semantics::types::{IdentifierTypeCell, TypeCell};
 use std::error::Error;
-use strum_macros::Display;
+use std::fmt;
 
-#[derive(Display, Debug)]
+#[derive(Debug)]
 pub enum SemanticError {
     InvalidType,
     TypeMismatch,
-    // UndefinedVariable,
-    // UnreachableCode,
-    // DivisionByZero,
-    // InvalidFunctionCall,
-    // UnsupportedOperation,
-    // MissingReturnValue,
-    // DuplicateVariable,
-    // UnusedVariable,
+    UndefinedVariable(String),
+    DivisionByZero,
     MissingIdentifier,
     MissingDataType,
-    #[strum(serialize = "undeclared type {0}")]
-    UndeclaredType(String),
-    UndeclaredIdentifier(String),
+    /// Undeclared name together with the declared name it was most likely meant to be.
+    UndeclaredType(String, Option<String>),
+    UndeclaredIdentifier(String, Option<String>),
     IncompatibleTypes(TypeCell, TypeCell),
     InvalidExpressionWithVariant(String, TypeCell),
     InvalidStructUsage(IdentifierTypeCell, TypeCell),
@@ -26,6 +21,356 @@ pub enum SemanticError {
     InvalidEnumVariant(String, TypeCell),
     RedeclaredIdentifier(String, String),
     VariantNotDeclared(TypeCell, String, IdentifierTypeCell),
+    NonConstantExpression(String),
+    AssignmentToConstant(String),
+    PatternTypeMismatch(String, DataType),
+    EmptyRange(String),
+    DuplicatePattern(String),
+    UnreachablePattern(String),
+    NonExhaustiveMatch(Vec<String>),
+    UndeclaredFunction(String),
+    InvalidFunctionCall(String),
+    ArgumentCountMismatch(String, usize, usize),
+    ArgumentTypeMismatch(String, TypeCell, DataType),
+    InvalidStatement(String),
+    MissingReturnValue(String),
+    ReturnValueInVoidFunction(String),
+    ReturnTypeMismatch(String, DataType, DataType),
+    NotAllPathsReturn(String),
+    InvalidAssignmentTarget(String),
+    UnsupportedOperation(String, DataType),
+    InvalidOperands(String, DataType, DataType),
+    InvalidUnaryOperand(String, DataType),
+    InvalidFieldAccess(String, DataType),
+    UndeclaredField(String, String, Option<String>),
+    InvalidCast(DataType, DataType),
+    NonBooleanCondition(DataType),
+    IntegerCondition(DataType),
+    AssignmentInCondition,
+    AssignmentInExpression,
+    VoidInitialiser(String),
+    MissingInitialiser(String),
+    UnassignedVariable(String),
+    UsedBeforeDeclaration(String),
+    UnknownAttribute(String),
+    UnknownLint(String),
+    /// `break` or `continue` with no loop around it.
+    JumpOutsideLoop(String),
+    /// Operation over constants whose result does not fit in its type.
+    ConstantOverflow(DataType),
+    ConstantOutOfRange(String, DataType),
+    MissingEntryPoint,
+    InvalidEntryPoint,
+    DuplicateEntryPoint,
+    /// Structure or enumeration that contains itself by value, with the fields leading back to
+    /// it.
+    RecursiveStruct(&'static str, String, String),
+    /// Explicit discriminant of a variant that is not an integer constant.
+    InvalidDiscriminant(String),
+    /// Variant whose discriminant is already used by an earlier variant.
+    DuplicateDiscriminant(String, i64, String),
+    /// Pattern binding a number of values other than its variant carries.
+    PatternBindingCount(String, usize, usize),
+    /// Pattern binding names in a case with several patterns.
+    AlternativeBindings(String),
+    /// Variant carrying data used without being constructed.
+    VariantNeedsPayload(String),
+    /// Structure given a number of type arguments other than its number of type parameters.
+    TypeArgumentCount(String, usize, usize),
+    /// Type parameter of a generic function that the arguments of a call do not determine.
+    CannotInferTypeParameter(String, String),
+    /// Generic function whose instances keep calling new, bigger instances of it.
+    RecursiveInstantiation(String),
+}
+
+impl fmt::Display for SemanticError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SemanticError::InvalidType => write!(f, "invalid type"),
+            SemanticError::TypeMismatch => write!(f, "mismatched types"),
+            SemanticError::UndefinedVariable(var) => {
+                write!(f, "variable '{var}' is used in its own initialiser")
+            }
+            SemanticError::DivisionByZero => write!(f, "division by zero"),
+            SemanticError::MissingIdentifier => write!(f, "missing identifier"),
+            SemanticError::MissingDataType => write!(f, "missing data type"),
+            SemanticError::UndeclaredType(data_type, suggestion) => {
+                write!(f, "undeclared type '{data_type}'")?;
+                write_suggestion(f, data_type, suggestion)
+            }
+            SemanticError::UndeclaredIdentifier(identifier, suggestion) => {
+                write!(f, "cannot find '{identifier}' in this scope")?;
+                write_suggestion(f, identifier, suggestion)
+            }
+            SemanticError::IncompatibleTypes(var, value) => write!(
+                f,
+                "cannot assign '{}' to variable '{}' of type '{}'",
+                value.data_type, var.identifier, var.data_type
+            ),
+            SemanticError::InvalidExpressionWithVariant(variant, var) => write!(
+                f,
+                "variant '{variant}' cannot be used in an expression with '{}' of type '{}'",
+                var.identifier, var.data_type
+            ),
+            SemanticError::InvalidStructUsage(structure, var) => write!(
+                f,
+                "invalid use of structure '{}' in the declaration of '{}'",
+                structure.identifier(),
+                var.identifier
+            ),
+            SemanticError::UnexpectedSymbolInExpression(symbol) => {
+                write!(f, "unexpected '{symbol}' in expression")
+            }
+            SemanticError::InvalidEnumVariant(variant, var) => write!(
+                f,
+                "'{variant}' is not a variant of '{}', the type of '{}'",
+                var.data_type, var.identifier
+            ),
+            SemanticError::RedeclaredIdentifier(original, redeclared) if original == redeclared => {
+                write!(f, "'{redeclared}' is declared more than once")
+            }
+            SemanticError::RedeclaredIdentifier(original, redeclared) => {
+                write!(f, "'{redeclared}' is already declared as '{original}'")
+            }
+            SemanticError::VariantNotDeclared(_, variant, enumeration) => write!(
+                f,
+                "enumeration '{}' has no variant '{variant}'",
+                enumeration.identifier()
+            ),
+            SemanticError::NonConstantExpression(identifier) => write!(
+                f,
+                "global initialisers must be constant, but '{identifier}' is not"
+            ),
+            SemanticError::AssignmentToConstant(identifier) => {
+                write!(f, "cannot assign to constant '{identifier}'")
+            }
+            SemanticError::PatternTypeMismatch(pattern, data_type) => write!(
+                f,
+                "pattern '{pattern}' does not match values of type '{data_type}'"
+            ),
+            SemanticError::EmptyRange(pattern) => {
+                write!(f, "range pattern '{pattern}' matches nothing")
+            }
+            SemanticError::DuplicatePattern(pattern) => {
+                write!(f, "pattern '{pattern}' is matched more than once")
+            }
+            SemanticError::UnreachablePattern(pattern) => write!(
+                f,
+                "pattern '{pattern}' is unreachable, earlier patterns already cover it"
+            ),
+            SemanticError::NonExhaustiveMatch(missing) => write!(
+                f,
+                "match is not exhaustive, missing '{}'",
+                missing.join("', '")
+            ),
+            SemanticError::UndeclaredFunction(identifier) => {
+                write!(f, "cannot find function '{identifier}'")
+            }
+            SemanticError::InvalidFunctionCall(identifier) => {
+                write!(f, "'{identifier}' is not a function")
+            }
+            SemanticError::ArgumentCountMismatch(identifier, expected, found) => write!(
+                f,
+                "function '{identifier}' takes {expected} argument(s) but {found} were given"
+            ),
+            SemanticError::ArgumentTypeMismatch(identifier, parameter, found) => write!(
+                f,
+                "argument '{}' of '{identifier}' expects '{}', found '{found}'",
+                parameter.identifier, parameter.data_type
+            ),
+            SemanticError::InvalidStatement(_) => {
+                write!(f, "only function calls can be used as statements")
+            }
+            SemanticError::MissingReturnValue(identifier) => {
+                write!(f, "function '{identifier}' must return a value")
+            }
+            SemanticError::ReturnValueInVoidFunction(identifier) => write!(
+                f,
+                "function '{identifier}' returns 'void' and cannot return a value"
+            ),
+            SemanticError::ReturnTypeMismatch(identifier, expected, found) => write!(
+                f,
+                "function '{identifier}' returns '{expected}', found '{found}'"
+            ),
+            SemanticError::NotAllPathsReturn(identifier) => write!(
+                f,
+                "not every path through function '{identifier}' returns a value"
+            ),
+            SemanticError::InvalidAssignmentTarget(identifier) => {
+                write!(f, "cannot assign to the result of calling '{identifier}'")
+            }
+            SemanticError::UnsupportedOperation(operator, data_type)
+            | SemanticError::InvalidUnaryOperand(operator, data_type) => {
+                write!(f, "operator '{operator}' cannot be applied to '{data_type}'")
+            }
+            SemanticError::InvalidOperands(operator, lhs, rhs) => write!(
+                f,
+                "operator '{operator}' cannot be applied to '{lhs}' and '{rhs}'"
+            ),
+            SemanticError::InvalidFieldAccess(field, data_type) => write!(
+                f,
+                "cannot access field '{field}' on a value of type '{data_type}'"
+            ),
+            SemanticError::UndeclaredField(structure, field, suggestion) => {
+                write!(f, "structure '{structure}' has no field '{field}'")?;
+                write_suggestion(f, field, suggestion)
+            }
+            SemanticError::InvalidCast(from, to) => write!(f, "cannot cast '{from}' to '{to}'"),
+            SemanticError::NonBooleanCondition(data_type) => {
+                write!(f, "condition must be 'boolean', found '{data_type}'")
+            }
+            SemanticError::IntegerCondition(data_type) => write!(
+                f,
+                "condition must be 'boolean', found '{data_type}'; compare it explicitly, e.g. 'x != 0'"
+            ),
+            SemanticError::AssignmentInCondition => {
+                write!(f, "assignment used as a condition; did you mean '=='?")
+            }
+            SemanticError::AssignmentInExpression => {
+                write!(f, "an assignment cannot be used as a value")
+            }
+            SemanticError::VoidInitialiser(var) => {
+                write!(f, "cannot infer a type for '{var}' from a 'void' value")
+            }
+            SemanticError::MissingInitialiser(var) => {
+                write!(f, "'{var}' must be given a value where it is declared")
+            }
+            SemanticError::UnassignedVariable(var) => {
+                write!(f, "variable '{var}' is read before a value is assigned to it on every path")
+            }
+            SemanticError::UsedBeforeDeclaration(var) => {
+                write!(f, "variable '{var}' is used before its declaration")
+            }
+            SemanticError::UnknownAttribute(attribute) => write!(
+                f,
+                "unknown attribute '{attribute}', expected 'allow', 'warn' or 'deny'"
+            ),
+            SemanticError::UnknownLint(code) => write!(f, "unknown lint '{code}'"),
+            SemanticError::ConstantOverflow(data_type) => {
+                write!(f, "constant expression overflows '{data_type}'")
+            }
+            SemanticError::ConstantOutOfRange(value, data_type) => {
+                write!(f, "constant '{value}' does not fit in '{data_type}'")
+            }
+            SemanticError::MissingEntryPoint => write!(
+                f,
+                "no 'main' function to start the program from, declare 'func int main()' or \
+                 compile a library with '--lib'"
+            ),
+            SemanticError::InvalidEntryPoint => write!(
+                f,
+                "'main' must be declared as 'func int main()' or 'func void main()'"
+            ),
+            SemanticError::DuplicateEntryPoint => {
+                write!(f, "a program has a single entry point, but 'main' is declared again")
+            }
+            SemanticError::RecursiveStruct(kind, identifier, cycle) => write!(
+                f,
+                "{kind} '{identifier}' contains itself by value and would have infinite size: {cycle}"
+            ),
+            SemanticError::JumpOutsideLoop(keyword) => {
+                write!(f, "'{keyword}' outside of a loop")
+            }
+            SemanticError::InvalidDiscriminant(variant) => write!(
+                f,
+                "discriminant of variant '{variant}' must be an integer constant"
+            ),
+            SemanticError::DuplicateDiscriminant(variant, value, earlier) => write!(
+                f,
+                "variant '{variant}' has discriminant {value}, which '{earlier}' already uses"
+            ),
+            SemanticError::PatternBindingCount(pattern, expected, found) => write!(
+                f,
+                "pattern '{pattern}' binds {found} value(s) but its variant carries {expected}"
+            ),
+            SemanticError::AlternativeBindings(pattern) => write!(
+                f,
+                "pattern '{pattern}' binds names, so it cannot share a case with other patterns"
+            ),
+            SemanticError::VariantNeedsPayload(variant) => write!(
+                f,
+                "variant '{variant}' carries data and must be constructed as '{variant}(...)'"
+            ),
+            SemanticError::TypeArgumentCount(identifier, expected, found) => write!(
+                f,
+                "type '{identifier}' takes {expected} type argument(s) but {found} were given"
+            ),
+            SemanticError::CannotInferTypeParameter(type_param, identifier) => write!(
+                f,
+                "type parameter '{type_param}' of '{identifier}' cannot be inferred from the arguments"
+            ),
+            SemanticError::RecursiveInstantiation(identifier) => write!(
+                f,
+                "generic function '{identifier}' instantiates itself with ever larger types"
+            ),
+        }
+    }
+}
+
+impl SemanticError {
+    /// Describes the related position attached to the error.
+    pub fn related_note(&self) -> &'static str {
+        match self {
+            SemanticError::UsedBeforeDeclaration(_) => "declared at",
+            SemanticError::DuplicateDiscriminant(..) => "first used at",
+            _ => "first declared at",
+        }
+    }
+}
+
+/// Appends the "did you mean" hint of an undeclared `name`, pointing out when only the case is
+/// wrong since that is easy to overlook.
+fn write_suggestion(
+    f: &mut fmt::Formatter<'_>,
+    name: &str,
+    suggestion: &Option<String>,
+) -> fmt::Result {
+    match suggestion {
+        Some(suggestion) if suggestion.eq_ignore_ascii_case(name) => {
+            write!(f, "; did you mean '{suggestion}'? names are case-sensitive")
+        }
+        Some(suggestion) => write!(f, "; did you mean '{suggestion}'?"),
+        None => Ok(()),
+    }
 }
 
 impl Error for SemanticError {}
+
+/// Suspicious code that does not stop compilation.
+#[derive(Debug)]
+pub enum SemanticWarning {
+    UnreachableDefault(String),
+    UnusedVariable(String),
+    UnusedParameter(String),
+    UnusedFunction(String),
+    UnusedType(String),
+    /// Statement that can never run, with the `return`, `break` or `continue` it follows.
+    UnreachableCode(Option<&'static str>),
+    ConstantCondition(bool),
+}
+
+impl fmt::Display for SemanticWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SemanticWarning::UnreachableDefault(scrutinee) => write!(
+                f,
+                "'default' is unreachable, every value of '{scrutinee}' is already matched"
+            ),
+            SemanticWarning::UnusedVariable(var) => write!(f, "variable '{var}' is never used"),
+            SemanticWarning::UnusedParameter(parameter) => {
+                write!(f, "parameter '{parameter}' is never used")
+            }
+            SemanticWarning::UnusedFunction(function) => {
+                write!(f, "function '{function}' is never called")
+            }
+            SemanticWarning::UnusedType(data_type) => write!(f, "type '{data_type}' is never used"),
+            SemanticWarning::UnreachableCode(Some(jump)) => {
+                write!(f, "unreachable code after '{jump}'")
+            }
+            SemanticWarning::UnreachableCode(None) => write!(f, "unreachable code"),
+            SemanticWarning::ConstantCondition(value) => {
+                write!(f, "condition is always '{value}'")
+            }
+        }
+    }
+}
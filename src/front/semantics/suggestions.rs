@@ -0,0 +1,42 @@
+/// Number of single character insertions, deletions and substitutions turning `from` into `to`.
+fn edit_distance(from: &str, to: &str) -> usize {
+    let to: Vec<char> = to.chars().collect();
+    let mut previous: Vec<usize> = (0..=to.len()).collect();
+    for (i, from_char) in from.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, to_char) in to.iter().enumerate() {
+            let substitution = previous[j] + usize::from(from_char != *to_char);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[to.len()]
+}
+
+/// Candidate closest to the misspelt `name`, if any is close enough to be what was meant.
+///
+/// A candidate differing only in case always wins; otherwise the nearest by edit distance is
+/// picked, allowing one edit per three characters of `name` and at least one. Ties go to the
+/// first candidate.
+pub fn closest_name<'a>(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<String> {
+    let candidates: Vec<&str> = candidates
+        .into_iter()
+        .filter(|candidate| *candidate != name)
+        .collect();
+    if let Some(candidate) = candidates
+        .iter()
+        .find(|candidate| candidate.eq_ignore_ascii_case(name))
+    {
+        return Some(candidate.to_string());
+    }
+    let allowed = (name.chars().count() / 3).max(1);
+    candidates
+        .iter()
+        .map(|candidate| (edit_distance(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= allowed)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate.to_string())
+}
@@ -1,59 +1,144 @@
-use crate::front::lexer::reserved::{ReservedWord, Separator};
-use crate::front::lexer::tokens::Token;
+use crate::front::lexer::reserved::Operator;
+use crate::front::lexer::tokens::{Literal, Token};
 use crate::front::parser::grammar::{DataType, NonTerminal, Symbol, Terminal, AST};
-use crate::front::semantics::semantic_errors::SemanticError;
+use crate::front::parser::syntax_tree::{
+    Assign, Attribute, Block, EnumDecl, Expr, FuncDecl, Item, Position, Stmnt, StructDecl,
+    SyntaxTree, TypedIdentifier, VarDecl, VariantPayload,
+};
+use crate::front::semantics::cfg::ControlFlowGraph;
+use crate::front::semantics::diagnostics::{Diagnostics, Report};
+use crate::front::semantics::expressions::{is_numeric, widens_to};
+use crate::front::semantics::flow::{block_declarations, Join};
+use crate::front::semantics::fold::fold;
+use crate::front::semantics::functions::{FuncSignature, FunctionTable, Target, ENTRY_POINT};
+use crate::front::semantics::generics::is_generic;
+use crate::front::semantics::layout::compute_layouts;
+use crate::front::semantics::lints::{Level, LINT_CODES};
+use crate::front::semantics::scope::ScopedTypeTable;
+use crate::front::semantics::semantic_errors::{SemanticError, SemanticWarning};
+use crate::front::semantics::suggestions::closest_name;
 
 pub type TypeTable = Vec<TypeCell>;
 pub type IdentifierTypeTable = Vec<IdentifierTypeCell>;
 
 #[derive(Debug, Clone)]
 pub struct TypeCell {
-    identifier: String,
-    data_type: DataType,
-    mutable: bool,
+    pub(crate) identifier: String,
+    pub(crate) data_type: DataType,
+    pub(crate) mutable: bool,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum IdentifierTypeCell {
     StructType {
         identifier: String,
+        /// Type parameters of a generic structure, which its fields' types may use.
+        type_params: Vec<String>,
         fields: TypeTable,
     },
     EnumType {
         identifier: String,
-        variants: Vec<String>,
+        variants: Vec<VariantCell>,
     },
 }
 
+#[derive(Debug, Clone)]
+pub struct VariantCell {
+    pub(crate) identifier: String,
+    pub(crate) discriminant: i64,
+    pub(crate) payload: VariantPayload,
+}
+
+impl VariantCell {
+    /// Values the variant must be constructed with, as the parameters of a function named after
+    /// it. Values of a tuple variant are numbered from 1.
+    pub(crate) fn parameters(&self) -> TypeTable {
+        let cell = |identifier: String, data_type: &DataType| TypeCell {
+            identifier,
+            data_type: data_type.clone(),
+            mutable: false,
+        };
+        match &self.payload {
+            VariantPayload::Unit => TypeTable::new(),
+            VariantPayload::Tuple(data_types) => data_types
+                .iter()
+                .enumerate()
+                .map(|(index, data_type)| cell((index + 1).to_string(), data_type))
+                .collect(),
+            VariantPayload::Struct(fields) => fields
+                .iter()
+                .map(|field| cell(field.identifier.clone(), &field.data_type))
+                .collect(),
+        }
+    }
+}
+
+impl IdentifierTypeCell {
+    pub fn identifier(&self) -> &str {
+        match self {
+            IdentifierTypeCell::StructType { identifier, .. }
+            | IdentifierTypeCell::EnumType { identifier, .. } => identifier,
+        }
+    }
+}
+
 impl AST {
-    pub fn new(raw_ast: Vec<(NonTerminal, Vec<Symbol>)>) -> Self {
-        Self(raw_ast)
+    pub fn new(raw_ast: Vec<(NonTerminal, Vec<Symbol>)>, positions: Vec<usize>) -> Self {
+        Self(raw_ast, positions)
     }
     pub fn as_vec(&self) -> &Vec<(NonTerminal, Vec<Symbol>)> {
         &self.0
     }
 
-    pub fn type_check(self) -> Result<(), Box<SemanticError>> {
-        let table = self.build_type_table()?;
-        let identifiers_as_types = self.get_valid_identifiers_as_types()?;
-        println!("Type table: {{");
-        table.iter().for_each(|cell| println!("  {:?}", cell));
-        println!("}}\n");
-        println!("ID as types: {{");
-        identifiers_as_types
-            .iter()
-            .for_each(|identifier| println!("  {:?}", identifier));
-        println!("}}\n");
-        self.validate_identifiers_as_types_usage(&table, &identifiers_as_types)?;
-        self.validate_declaration_expression(&table, &identifiers_as_types)
+    pub fn type_check(self, target: Target) -> Report {
+        let tree = self.syntax_tree();
+        let mut diagnostics = Diagnostics::default();
+        tree.check_entry_point(target, &mut diagnostics);
+        let identifiers_as_types = tree.get_valid_identifiers_as_types(&mut diagnostics);
+        let recursive = tree.check_recursive_structs(&identifiers_as_types, &mut diagnostics);
+        let functions = tree.build_function_table(target, &mut diagnostics);
+        let mut checker = TypeChecker::new(&identifiers_as_types, &functions);
+        checker.diagnostics = diagnostics;
+        checker.check(&tree, target);
+        let mut report = checker.diagnostics.into_report();
+        report.layouts = compute_layouts(
+            &identifiers_as_types,
+            &recursive,
+            &checker.instantiated_types,
+        );
+        report.instances = checker.instances.iter().map(instance_name).collect();
+        report.tables = [
+            table(
+                "ID as types",
+                identifiers_as_types.iter().map(|cell| format!("{cell:?}")),
+            ),
+            table(
+                "Function table",
+                functions.iter().map(|cell| format!("{cell:?}")),
+            ),
+            table(
+                "Type table",
+                checker.declared.iter().map(|cell| format!("{cell:?}")),
+            ),
+            table(
+                "Inferred types",
+                checker
+                    .inferred
+                    .iter()
+                    .map(|cell| format!("{}: {}", cell.identifier, cell.data_type)),
+            ),
+            table("Instances", checker.instances.iter().map(instance_name)),
+        ]
+        .concat();
+        report
     }
 
-    fn not_epsilon(prod: &[Symbol]) -> bool {
+    pub(crate) fn not_epsilon(prod: &[Symbol]) -> bool {
         prod.first()
             .is_some_and(|s| !matches!(s, Symbol::Terminal(Terminal::Epsilon)))
     }
 
-    fn get_identifier_in_production(production: &[Symbol]) -> Option<String> {
+    pub(crate) fn get_identifier_in_production(production: &[Symbol]) -> Option<String> {
         production.iter().find_map(|symbol| {
             if let Symbol::Terminal(Terminal::Token(Token::Identifier(identifier))) = symbol {
                 Some(identifier.clone())
@@ -63,238 +148,6 @@ impl AST {
         })
     }
 
-    fn build_type_table(&self) -> Result<TypeTable, Box<SemanticError>> {
-        let mut table = Vec::new();
-        for (non_terminal, production) in self.as_vec() {
-            match non_terminal {
-                NonTerminal::StmntDecl => table.push(TypeCell {
-                    identifier: match Self::get_identifier_in_production(production) {
-                        Some(name) => name,
-                        None => continue,
-                    },
-                    data_type: match production.iter().find_map(|symbol| {
-                        if let Symbol::Terminal(Terminal::DataType(dt)) = symbol {
-                            Some(dt.clone())
-                        } else {
-                            None
-                        }
-                    }) {
-                        Some(dt) => dt,
-                        None => continue,
-                    },
-                    mutable: production
-                        .iter()
-                        .find_map(|symbol| match symbol {
-                            Symbol::Terminal(Terminal::Token(Token::ReservedWord(
-                                ReservedWord::Let,
-                            ))) => Some(true),
-                            Symbol::Terminal(Terminal::Token(Token::ReservedWord(
-                                ReservedWord::Constant,
-                            ))) => Some(false),
-                            _ => None,
-                        })
-                        .unwrap(),
-                }),
-                // NonTerminal::
-                _ => continue,
-            }
-        }
-        Ok(table)
-    }
-    //fixme check for redeclaring id on variants and fields if variants have same id as struct name, as well as variant repetition
-    //fixme check for redeclaring id on struct fields
-    fn get_valid_identifiers_as_types(&self) -> Result<IdentifierTypeTable, Box<SemanticError>> {
-        let mut ast_vec = self.as_vec().iter();
-        let mut valid_types: Vec<IdentifierTypeCell> = Vec::new();
-        while let Some((non_terminal, production)) = ast_vec.next() {
-            match non_terminal {
-                NonTerminal::Enum => {
-                    let variants: Vec<String> = ast_vec
-                        .by_ref()
-                        .take_while(|(nt, prod)| {
-                            nt == &NonTerminal::EnumBody && Self::not_epsilon(prod)
-                        })
-                        .filter_map(|(_, prod)| match prod.first()? {
-                            Symbol::Terminal(Terminal::Token(Token::Separator(
-                                Separator::Comma,
-                            ))) => None,
-                            Symbol::Terminal(Terminal::Token(Token::Identifier(variant))) => {
-                                Some(variant.clone())
-                            }
-                            _ => panic!("Unexpected symbol in enum body: {:?}", prod.first()),
-                        })
-                        .collect();
-                    valid_types.push(IdentifierTypeCell::EnumType {
-                        identifier: Self::get_identifier_in_production(production).unwrap(),
-                        variants,
-                    });
-                }
-                NonTerminal::Struct => {
-                    // No id in prod returns syntax error, thus, unwrapping makes more sense.
-                    let identifier = Self::get_identifier_in_production(production).unwrap();
-                    let fields: TypeTable = ast_vec
-                        .by_ref()
-                        .take_while(|(nt, prod)| {
-                            nt == &NonTerminal::StructBody && Self::not_epsilon(prod)
-                        })
-                        .filter_map(|(_, prod)| {
-                            let mut prod_iter = prod.iter();
-                            match prod_iter.next()? {
-                                Symbol::Terminal(Terminal::DataType(dt)) => Some(TypeCell {
-                                    identifier: Self::get_identifier_in_production(prod).unwrap(),
-                                    data_type: dt.clone(),
-                                    mutable: true,
-                                }),
-                                _ => None,
-                            }
-                        })
-                        .collect();
-                    valid_types.push(IdentifierTypeCell::StructType { identifier, fields })
-                }
-                _ => {}
-            }
-        }
-        Ok(valid_types)
-    }
-
-    fn validate_identifiers_as_types_usage(
-        &self,
-        type_table: &TypeTable,
-        valid_id_types: &IdentifierTypeTable,
-    ) -> Result<(), Box<SemanticError>> {
-        type_table
-            .iter()
-            .filter_map(|cell| match &cell.data_type {
-                DataType::Identifier(identifier) => Some(identifier),
-                _ => None,
-            })
-            .find(|&identifier| {
-                !valid_id_types
-                    .iter()
-                    .map(|valid_t| match valid_t {
-                        IdentifierTypeCell::StructType { identifier, .. }
-                        | IdentifierTypeCell::EnumType { identifier, .. } => identifier,
-                    })
-                    .collect::<Vec<&String>>()
-                    .contains(&identifier)
-            })
-            .map_or(Ok(()), |invalid_id| {
-                Err(Box::from(SemanticError::UndeclaredType(invalid_id.clone())))
-            })
-    }
-
-    fn validate_declaration_expression(
-        &self,
-        type_table: &TypeTable,
-        valid_id_types: &IdentifierTypeTable,
-    ) -> Result<(), Box<SemanticError>> {
-        let mut ast_vec = self.as_vec().iter().peekable();
-
-        while let Some((nt, prod)) = ast_vec.by_ref().next() {
-            match nt {
-                NonTerminal::StmntDecl => {
-                    let var = Self::find_var_in_table(
-                        type_table,
-                        &Self::get_identifier_in_production(prod).unwrap(),
-                    )
-                    .unwrap();
-
-                    let expr_operands: Vec<&(NonTerminal, Vec<Symbol>)> = ast_vec
-                        .by_ref()
-                        .peekable()
-                        .take_while(|(_, prod)| Self::not_epsilon(prod))
-                        .collect();
-
-                    for (nt, prod) in expr_operands {
-                        let mut prod = prod.iter().peekable();
-                        match nt {
-                            NonTerminal::ExprOperand => {
-                                if let Some(symbol) = prod.next() {
-                                    match symbol {
-                                        Symbol::Terminal(Terminal::Token(Token::Identifier(
-                                            expr_id,
-                                        ))) => {
-                                            if let DataType::Identifier(_) = var.data_type {
-                                                return Self::validate_id_type_expr(
-                                                    valid_id_types,
-                                                    var,
-                                                    expr_id,
-                                                );
-                                            }
-                                            match Self::find_var_in_table(type_table, expr_id) {
-                                                Some(expr_var) => {
-                                                    if expr_var.data_type != var.data_type {
-                                                        return Err(Box::from(
-                                                            SemanticError::IncompatibleTypes(
-                                                                var.clone(),
-                                                                expr_var.clone(),
-                                                            ),
-                                                        ));
-                                                    }
-                                                }
-                                                None => {
-                                                    return Err(Box::from(
-                                                        SemanticError::UndeclaredIdentifier(
-                                                            expr_id.clone(),
-                                                        ),
-                                                    ))
-                                                }
-                                            }
-                                        }
-                                        Symbol::Terminal(Terminal::Token(Token::Literal(
-                                            expr_literal,
-                                        ))) => {
-                                            if var.data_type
-                                                != DataType::from(expr_literal.to_owned())
-                                                || matches!(var.data_type, DataType::Identifier(_))
-                                            {
-                                                return Err(Box::from(
-                                                    SemanticError::IncompatibleTypes(
-                                                        var.to_owned(),
-                                                        TypeCell {
-                                                            identifier: "".to_string(),
-                                                            data_type: DataType::from(
-                                                                expr_literal.to_owned(),
-                                                            ),
-                                                            mutable: false,
-                                                        },
-                                                    ),
-                                                ));
-                                            }
-                                            return Ok(());
-                                        }
-                                        symbol => {
-                                            panic!("Unexpected symbol: {:?}", symbol);
-                                        }
-                                    }
-                                }
-                            }
-                            NonTerminal::ExprOperation => {
-                                if let Some(symbol) = prod.next() {
-                                    //fixme deal with operator types by refactoring Terminal::UnaryOperator
-                                    match symbol {
-                                        Symbol::NonTerminal(_) => {}
-                                        Symbol::Terminal(_) => {}
-                                    }
-                                }
-                            }
-                            NonTerminal::Expr
-                            | NonTerminal::StmntList
-                            | NonTerminal::ExprCall => continue,
-                            symbol => {
-                                // Handle other unexpected symbols gracefully
-                                panic!("Unexpected symbol: {:?}", symbol);
-                            }
-                        }
-                    }
-                }
-                NonTerminal::StmntAssign => {}
-                _ => continue,
-            }
-        }
-        Ok(())
-    }
-
     fn validate_id_type_expr(
         valid_id_types: &IdentifierTypeTable,
         var: &TypeCell,
@@ -307,7 +160,10 @@ impl AST {
                     variants,
                 } => {
                     if var.data_type == DataType::Identifier(identifier.to_owned()) {
-                        if variants.iter().any(|variant| variant == expr_id) {
+                        if variants
+                            .iter()
+                            .any(|variant| variant.identifier == *expr_id)
+                        {
                             return Ok(());
                         }
                         return Err(Box::from(SemanticError::VariantNotDeclared(
@@ -320,7 +176,9 @@ impl AST {
                         )));
                     }
                 }
-                IdentifierTypeCell::StructType { identifier, fields } => {
+                IdentifierTypeCell::StructType {
+                    identifier, fields, ..
+                } => {
                     // fixme match case on &var could provide more and better error treatment concisely
                     if identifier == &var.identifier {
                         return Err(Box::from(SemanticError::RedeclaredIdentifier(
@@ -335,27 +193,950 @@ impl AST {
                             .any(|field| field.identifier == var.identifier)
                     {
                         return Err(Box::from(SemanticError::InvalidStructUsage(
-                            IdentifierTypeCell::StructType {
-                                identifier: identifier.to_owned(),
-                                fields: fields.to_owned(),
-                            },
+                            valid_type.clone(),
                             var.clone(),
                         )));
                     }
                 }
             }
         }
+        let data_type = var.data_type.to_string();
+        let suggestion = suggest_type(valid_id_types, &data_type);
         Err(Box::from(SemanticError::UndeclaredType(
-            var.data_type.to_string(),
+            data_type, suggestion,
         )))
     }
+}
+
+/// Level each lint is set to by function `attributes`, the last attribute naming a lint winning.
+/// Invalid attributes are reported when the function is checked and ignored here.
+fn lint_levels(attributes: &[Attribute]) -> Vec<(String, Level)> {
+    attributes
+        .iter()
+        .filter_map(|attribute| Some((attribute.name.parse::<Level>().ok()?, attribute)))
+        .flat_map(|(level, attribute)| {
+            attribute
+                .lints
+                .iter()
+                .map(move |(code, _)| (code.clone(), level))
+        })
+        .collect()
+}
+
+/// The `entries` of the table `title`, one per line.
+fn table(title: &str, entries: impl Iterator<Item = String>) -> String {
+    let entries: String = entries.map(|entry| format!("  {entry}\n")).collect();
+    format!("{title}: {{\n{entries}}}\n\n")
+}
+
+/// A generic function with the type arguments of one of its instances, such as `max<int>`.
+fn instance_name((identifier, arguments): &(String, Vec<DataType>)) -> String {
+    DataType::Applied(identifier.clone(), arguments.clone()).to_string()
+}
+
+/// Type of a variable whose type could not be inferred. No structure or enumeration can be named
+/// with an empty identifier, so the variable counts as poisoned and its uses are not reported.
+pub(crate) fn unknown_type() -> DataType {
+    DataType::Identifier(String::new())
+}
+
+/// Names of the builtin types a variable can have.
+fn builtin_types() -> [String; 7] {
+    [
+        DataType::Int,
+        DataType::Long,
+        DataType::Float,
+        DataType::Double,
+        DataType::Str,
+        DataType::Char,
+        DataType::Bool,
+    ]
+    .map(|data_type| data_type.to_string())
+}
+
+/// Builtin or declared type most likely meant by the undeclared `type_identifier`.
+pub(crate) fn suggest_type(
+    valid_id_types: &IdentifierTypeTable,
+    type_identifier: &str,
+) -> Option<String> {
+    let builtins = builtin_types();
+    closest_name(
+        type_identifier,
+        builtins
+            .iter()
+            .map(String::as_str)
+            .chain(valid_id_types.iter().map(IdentifierTypeCell::identifier)),
+    )
+}
+
+impl SyntaxTree {
+    /// Collects every `struct` and `enumeration`. A type, field or variant whose name is already
+    /// taken is reported and its first declaration kept; so is a variant named like a type, as
+    /// it would be ambiguous wherever a type is expected.
+    fn get_valid_identifiers_as_types(&self, diagnostics: &mut Diagnostics) -> IdentifierTypeTable {
+        let declarations = self.items.iter().filter_map(|item| match item {
+            Item::Struct(structure) => {
+                Some((structure.identifier.as_str(), structure.position, item))
+            }
+            Item::Enum(enumeration) => {
+                Some((enumeration.identifier.as_str(), enumeration.position, item))
+            }
+            _ => None,
+        });
+        let declarations = diagnostics.first_declarations(declarations);
+        let type_positions: Vec<(&str, Position)> = declarations
+            .iter()
+            .filter_map(|item| match item {
+                Item::Struct(structure) => {
+                    Some((structure.identifier.as_str(), structure.position))
+                }
+                Item::Enum(enumeration) => {
+                    Some((enumeration.identifier.as_str(), enumeration.position))
+                }
+                _ => None,
+            })
+            .collect();
+        declarations
+            .into_iter()
+            .filter_map(|item| match item {
+                Item::Struct(structure) => Some(IdentifierTypeCell::StructType {
+                    identifier: structure.identifier.clone(),
+                    type_params: diagnostics.first_declarations(structure.type_params.iter().map(
+                        |(type_param, position)| {
+                            (type_param.as_str(), *position, type_param.clone())
+                        },
+                    )),
+                    fields: diagnostics.first_declarations(structure.fields.iter().map(|field| {
+                        let cell = TypeCell {
+                            identifier: field.identifier.clone(),
+                            data_type: field.data_type.clone(),
+                            mutable: true,
+                        };
+                        (field.identifier.as_str(), field.position, cell)
+                    })),
+                }),
+                Item::Enum(enumeration) => Some(IdentifierTypeCell::EnumType {
+                    identifier: enumeration.identifier.clone(),
+                    variants: enumeration_variants(enumeration, &type_positions, diagnostics),
+                }),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+/// Variants of `enumeration` with their discriminants. Variants without an explicit `= value`
+/// follow the previous one, starting from 0. A discriminant that is not an integer constant or
+/// that is taken by an earlier variant is reported, as are payload fields declared twice.
+fn enumeration_variants(
+    enumeration: &EnumDecl,
+    type_positions: &[(&str, Position)],
+    diagnostics: &mut Diagnostics,
+) -> Vec<VariantCell> {
+    let variants = enumeration.variants.iter().filter(|variant| {
+        match type_positions
+            .iter()
+            .find(|(name, _)| *name == variant.identifier)
+        {
+            Some((_, type_position)) => {
+                diagnostics.report_related(
+                    variant.position,
+                    SemanticError::RedeclaredIdentifier(
+                        variant.identifier.clone(),
+                        variant.identifier.clone(),
+                    ),
+                    *type_position,
+                );
+                false
+            }
+            None => true,
+        }
+    });
+    let variants: Vec<_> = variants
+        .map(|variant| (variant.identifier.as_str(), variant.position, variant))
+        .collect();
+    let mut cells: Vec<VariantCell> = Vec::new();
+    let mut taken: Vec<(i64, &str, Position)> = Vec::new();
+    let mut next = 0;
+    for variant in diagnostics.first_declarations(variants) {
+        let discriminant = match &variant.discriminant {
+            Some(value) => match fold(value) {
+                Some(Literal::Int(value)) => i64::from(value),
+                Some(Literal::Long(value)) => value,
+                _ => {
+                    diagnostics.report(
+                        variant.position,
+                        SemanticError::InvalidDiscriminant(variant.identifier.clone()),
+                    );
+                    next
+                }
+            },
+            None => next,
+        };
+        match taken.iter().find(|(value, ..)| *value == discriminant) {
+            Some((_, earlier, earlier_position)) => diagnostics.report_related(
+                variant.position,
+                SemanticError::DuplicateDiscriminant(
+                    variant.identifier.clone(),
+                    discriminant,
+                    earlier.to_string(),
+                ),
+                *earlier_position,
+            ),
+            None => taken.push((discriminant, &variant.identifier, variant.position)),
+        }
+        next = discriminant.wrapping_add(1);
+        let payload = match &variant.payload {
+            VariantPayload::Struct(fields) => VariantPayload::Struct(
+                diagnostics.first_declarations(
+                    fields
+                        .iter()
+                        .map(|field| (field.identifier.as_str(), field.position, field.clone())),
+                ),
+            ),
+            payload => payload.clone(),
+        };
+        cells.push(VariantCell {
+            identifier: variant.identifier.clone(),
+            discriminant,
+            payload,
+        });
+    }
+    cells
+}
+
+/// Walks the syntax tree keeping track of which variables are visible from each block.
+pub(crate) struct TypeChecker<'a> {
+    pub(crate) valid_id_types: &'a IdentifierTypeTable,
+    pub(crate) functions: &'a FunctionTable,
+    pub(crate) scopes: ScopedTypeTable,
+    /// Signature of the function whose body is being checked.
+    pub(crate) current_function: Option<FuncSignature>,
+    /// Every binding declared so far, in source order, for `--print-tables`.
+    declared: TypeTable,
+    /// Bindings declared without a type, with the type inferred for them, for `--print-tables`.
+    inferred: TypeTable,
+    /// Variable whose initialiser is being checked, which cannot refer to itself.
+    pub(crate) initialising: Option<String>,
+    /// Every variable declared in the body of the function being checked.
+    pub(crate) function_declarations: Vec<(String, Position)>,
+    pub(crate) diagnostics: Diagnostics,
+    /// Lint levels set by the attributes of the function being checked.
+    pub(crate) function_lints: Vec<(String, Level)>,
+    /// Functions called from another function, and structures and enumerations referred to.
+    pub(crate) called: Vec<String>,
+    pub(crate) used_types: Vec<String>,
+    /// Variables assigned at each `break` of every loop around the statement being checked,
+    /// innermost last.
+    pub(crate) loop_exits: Vec<Join>,
+    /// Matches of the function being checked whose cases alone cover every value.
+    pub(crate) exhaustive_matches: Vec<Position>,
+    /// Type arguments each generic function is called with, in the order first called.
+    pub(crate) instances: Vec<(String, Vec<DataType>)>,
+    /// Generic structures given concrete type arguments, each laid out on its own.
+    pub(crate) instantiated_types: Vec<DataType>,
+}
+
+impl<'a> TypeChecker<'a> {
+    fn new(valid_id_types: &'a IdentifierTypeTable, functions: &'a FunctionTable) -> Self {
+        Self {
+            valid_id_types,
+            functions,
+            scopes: ScopedTypeTable::default(),
+            current_function: None,
+            declared: TypeTable::new(),
+            inferred: TypeTable::new(),
+            initialising: None,
+            function_declarations: Vec::new(),
+            diagnostics: Diagnostics::default(),
+            function_lints: Vec::new(),
+            called: Vec::new(),
+            used_types: Vec::new(),
+            loop_exits: Vec::new(),
+            exhaustive_matches: Vec::new(),
+            instances: Vec::new(),
+            instantiated_types: Vec::new(),
+        }
+    }
+
+    fn check(&mut self, tree: &SyntaxTree, target: Target) {
+        // Globals live in the outermost scope and are declared before any function is checked,
+        // so every function sees every global regardless of declaration order.
+        self.scopes.enter_scope();
+        for item in &tree.items {
+            if let Item::Global(global) = item {
+                match &global.value {
+                    Some(value) => {
+                        if let Err(error) = self.validate_constant_expression(value) {
+                            self.diagnostics.report(value.position(), *error);
+                        }
+                    }
+                    // Functions may run in any order, so none of them could assign it first.
+                    None => self.diagnostics.report(
+                        global.position,
+                        SemanticError::MissingInitialiser(global.identifier.clone()),
+                    ),
+                }
+                self.check_declaration(global);
+            }
+        }
+        for item in &tree.items {
+            match item {
+                Item::Struct(structure) => {
+                    for field in &structure.fields {
+                        self.check_type_exists(&field.data_type, field.position);
+                    }
+                }
+                Item::Enum(enumeration) => {
+                    for variant in &enumeration.variants {
+                        for data_type in variant.payload.data_types() {
+                            self.check_type_exists(data_type, variant.position);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        for item in &tree.items {
+            if let Item::Func(func) = item {
+                self.check_function(func, self.find_function(&func.identifier).cloned());
+            }
+        }
+        // Generic functions were checked with their type parameters standing for no type in
+        // particular, and are checked again for the types they are called with, once the calls
+        // outside of them are known.
+        self.check_instances(tree);
+        self.exit_scope(&[]);
+        self.warn_unused_items(tree, target);
+    }
+
+    /// Warns about functions never called, except the entry point `main`, and about structures
+    /// and enumerations never referred to. A library's functions are called by the programs using
+    /// it, so they are never warned about.
+    fn warn_unused_items(&mut self, tree: &SyntaxTree, target: Target) {
+        // A structure used by another one's field counts as used.
+        for valid_type in self.valid_id_types {
+            if let IdentifierTypeCell::StructType { fields, .. } = valid_type {
+                for field in fields {
+                    self.mark_type_used(&field.data_type);
+                }
+            }
+        }
+        // A type declared again was reported as such, and only its first declaration is used.
+        let mut declared_types: Vec<&str> = Vec::new();
+        for item in &tree.items {
+            if let Item::Struct(StructDecl { identifier, .. })
+            | Item::Enum(EnumDecl { identifier, .. }) = item
+            {
+                if declared_types.contains(&identifier.as_str()) {
+                    continue;
+                }
+                declared_types.push(identifier);
+            }
+            let (identifier, position, warning) = match item {
+                Item::Func(func) if target == Target::Library || func.identifier == ENTRY_POINT => {
+                    continue
+                }
+                Item::Func(func) => (
+                    &func.identifier,
+                    func.position,
+                    SemanticWarning::UnusedFunction(func.identifier.clone()),
+                ),
+                Item::Struct(structure) => (
+                    &structure.identifier,
+                    structure.position,
+                    SemanticWarning::UnusedType(structure.identifier.clone()),
+                ),
+                Item::Enum(enumeration) => (
+                    &enumeration.identifier,
+                    enumeration.position,
+                    SemanticWarning::UnusedType(enumeration.identifier.clone()),
+                ),
+                _ => continue,
+            };
+            let used = match item {
+                Item::Func(_) => self.called.contains(identifier),
+                _ => self.used_types.contains(identifier),
+            };
+            if !used {
+                // A function's own attributes apply to the warning about it.
+                if let Item::Func(func) = item {
+                    self.function_lints = lint_levels(&func.attributes);
+                }
+                self.warn_unused(identifier, position, warning);
+                self.function_lints.clear();
+            }
+        }
+    }
 
-    fn find_var_in_table<'a>(
-        type_table: &'a [TypeCell],
-        identifier: &String,
-    ) -> Option<&'a TypeCell> {
-        type_table
+    pub(crate) fn warn(&mut self, position: Position, warning: SemanticWarning) {
+        let level = self
+            .function_lints
             .iter()
-            .find(|cell| cell.identifier == *identifier)
+            .rev()
+            .find(|(code, _)| code == warning.code())
+            .map(|(_, level)| *level);
+        self.diagnostics.warn(position, warning, level);
+    }
+
+    /// Reports attributes that are not lint levels and lints that do not exist.
+    fn check_attributes(&mut self, attributes: &[Attribute]) {
+        for attribute in attributes {
+            if attribute.name.parse::<Level>().is_err() {
+                self.diagnostics.report(
+                    attribute.position,
+                    SemanticError::UnknownAttribute(attribute.name.clone()),
+                );
+                continue;
+            }
+            for (code, position) in &attribute.lints {
+                if !LINT_CODES.contains(&code.as_str()) {
+                    self.diagnostics
+                        .report(*position, SemanticError::UnknownLint(code.clone()));
+                }
+            }
+        }
+    }
+
+    /// Names starting with `_` are unused on purpose and never warned about.
+    fn warn_unused(&mut self, identifier: &str, position: Position, warning: SemanticWarning) {
+        if !identifier.starts_with('_') {
+            self.warn(position, warning);
+        }
+    }
+
+    /// Marks the structures and enumerations `data_type` refers to as used, including the type
+    /// arguments of a generic structure, which is then laid out for them.
+    pub(crate) fn mark_type_used(&mut self, data_type: &DataType) {
+        match data_type {
+            DataType::Identifier(identifier) if !self.used_types.contains(identifier) => {
+                self.used_types.push(identifier.clone());
+            }
+            DataType::Applied(identifier, arguments) => {
+                self.mark_type_used(&DataType::Identifier(identifier.clone()));
+                for argument in arguments {
+                    self.mark_type_used(argument);
+                }
+                if !is_generic(data_type) && !self.instantiated_types.contains(data_type) {
+                    self.instantiated_types.push(data_type.clone());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Checks the body of `func` as a function with `signature`. Values of a type parameter
+    /// could be of any type, so nothing is checked about them.
+    pub(crate) fn check_function(&mut self, func: &FuncDecl, signature: Option<FuncSignature>) {
+        self.check_attributes(&func.attributes);
+        self.diagnostics.first_declarations(
+            func.type_params
+                .iter()
+                .map(|(type_param, position)| (type_param.as_str(), *position, ())),
+        );
+        self.function_lints = lint_levels(&func.attributes);
+        self.scopes.enter_scope();
+        // The return type comes right after `func`.
+        self.check_type_exists(&func.return_type, func.position + 1);
+        for argument in &func.arguments {
+            self.check_type_exists(&argument.data_type, argument.position);
+            self.declare(
+                TypeCell {
+                    identifier: argument.identifier.clone(),
+                    data_type: argument.data_type.clone(),
+                    mutable: true,
+                },
+                argument.position,
+                true,
+            );
+        }
+        self.current_function = signature;
+        self.exhaustive_matches.clear();
+        self.function_declarations = block_declarations(&func.body);
+        // The body shares the arguments' scope, so a `let` cannot shadow an argument.
+        self.check_stmnts(&func.body);
+        self.exit_scope(&func.arguments);
+        let cfg = ControlFlowGraph::new(&func.body, &self.exhaustive_matches);
+        for (position, jump) in cfg.unreachable() {
+            self.warn(position, SemanticWarning::UnreachableCode(jump));
+        }
+        if func.return_type != DataType::Void && cfg.falls_through() {
+            self.diagnostics.report(
+                func.position,
+                SemanticError::NotAllPathsReturn(func.identifier.clone()),
+            );
+        }
+        self.function_lints.clear();
+    }
+
+    pub(crate) fn check_block(&mut self, block: &Block) {
+        self.scopes.enter_scope();
+        self.check_stmnts(block);
+        self.exit_scope(&[]);
+    }
+
+    /// Closes the innermost scope, warning about variables and function `arguments` that were
+    /// never read.
+    pub(crate) fn exit_scope(&mut self, arguments: &[TypedIdentifier]) {
+        for (cell, position) in self.scopes.exit_scope() {
+            let warning = match arguments
+                .iter()
+                .any(|argument| argument.identifier == cell.identifier)
+            {
+                true => SemanticWarning::UnusedParameter(cell.identifier.clone()),
+                false => SemanticWarning::UnusedVariable(cell.identifier.clone()),
+            };
+            self.warn_unused(&cell.identifier, position, warning);
+        }
+    }
+
+    fn check_stmnts(&mut self, block: &Block) {
+        for stmnt in block {
+            self.check_stmnt(stmnt);
+        }
+    }
+
+    pub(crate) fn check_stmnt(&mut self, stmnt: &Stmnt) {
+        match stmnt {
+            Stmnt::Decl(decl) => self.check_declaration(decl),
+            Stmnt::If {
+                branches,
+                otherwise,
+                ..
+            } => {
+                let before = self.scopes.assignments();
+                let mut join = Join::default();
+                for (condition, body) in branches {
+                    self.check_condition(condition);
+                    self.warn_constant_condition(condition);
+                    self.check_branch(body, &before, &mut join);
+                }
+                match otherwise {
+                    Some(body) => self.check_branch(body, &before, &mut join),
+                    // Without an `else` no branch may run at all.
+                    None => join.path(before.clone()),
+                }
+                self.scopes.restore_assignments(&join.finish(before));
+            }
+            Stmnt::Match {
+                scrutinee,
+                cases,
+                default,
+                position,
+            } => self.check_match(scrutinee, cases, default, *position),
+            Stmnt::For {
+                init,
+                condition,
+                step,
+                body,
+                ..
+            } => {
+                // The loop variable lives in its own scope around the body.
+                self.scopes.enter_scope();
+                self.check_declaration(init);
+                self.check_condition(condition);
+                self.check_loop_body(condition, body, Some(step));
+                self.exit_scope(&[]);
+            }
+            Stmnt::While {
+                condition, body, ..
+            } => {
+                self.check_condition(condition);
+                self.warn_constant_condition(condition);
+                self.check_loop_body(condition, body, None);
+            }
+            Stmnt::Break(position) => {
+                self.check_jump("break", *position);
+                let assignments = self.scopes.assignments();
+                if let Some(exits) = self.loop_exits.last_mut() {
+                    exits.path(assignments);
+                }
+            }
+            Stmnt::Continue(position) => self.check_jump("continue", *position),
+            Stmnt::Block(body, _) => self.check_block(body),
+            Stmnt::Assign(assign) => self.check_assignment(assign),
+            Stmnt::Return(value, position) => self.check_return(value, *position),
+            Stmnt::Expr(expr, position) => match expr {
+                Expr::Call { .. } => {
+                    self.expression_type(expr);
+                }
+                _ => self.diagnostics.report(
+                    *position,
+                    SemanticError::InvalidStatement(
+                        expr.root_identifier().unwrap_or_default().to_string(),
+                    ),
+                ),
+            },
+        }
+    }
+
+    /// Conditions of `if`, `elif`, `while` and `for` must be `boolean`, there is no implicit
+    /// truthiness of numbers.
+    fn check_condition(&mut self, condition: &Expr) {
+        if let Expr::Binary {
+            operator: Operator::Assignment,
+            position,
+            ..
+        } = condition
+        {
+            self.diagnostics
+                .report(*position, SemanticError::AssignmentInCondition);
+            return;
+        }
+        let position = condition.position();
+        let error = match self.expression_type(condition) {
+            None | Some(DataType::Bool) => return,
+            Some(data_type @ (DataType::Int | DataType::Long)) => {
+                SemanticError::IntegerCondition(data_type)
+            }
+            Some(data_type) => SemanticError::NonBooleanCondition(data_type),
+        };
+        self.diagnostics.report(position, error);
+    }
+
+    pub(crate) fn declare(&mut self, cell: TypeCell, position: Position, assigned: bool) {
+        self.declared.push(cell.clone());
+        let identifier = cell.identifier.clone();
+        if let Err(original) = self.scopes.declare(cell, position, assigned) {
+            self.diagnostics.report_related(
+                position,
+                SemanticError::RedeclaredIdentifier(identifier.clone(), identifier),
+                original,
+            );
+        }
+    }
+
+    fn check_declaration(&mut self, decl: &VarDecl) {
+        // The initialiser is checked before declaring, so it only sees enclosing bindings.
+        self.initialising = Some(decl.identifier.clone());
+        let data_type = match &decl.data_type {
+            Some(data_type) => {
+                self.check_declared_type(decl, data_type);
+                data_type.clone()
+            }
+            None => self.infer_declared_type(decl),
+        };
+        self.initialising = None;
+        let var = TypeCell {
+            identifier: decl.identifier.clone(),
+            data_type,
+            mutable: decl.mutable,
+        };
+        if decl.data_type.is_none() {
+            self.inferred.push(var.clone());
+        }
+        if decl.value.is_none() && !decl.mutable {
+            self.diagnostics.report(
+                decl.position,
+                SemanticError::MissingInitialiser(decl.identifier.clone()),
+            );
+        }
+        // The variable is declared even when invalid, so its uses are not reported as undeclared.
+        // Only variables left without a value need to be assigned before they are read.
+        let assigned = decl.value.is_some() || !decl.mutable;
+        self.declare(var, decl.position, assigned);
+    }
+
+    /// Reports `data_type`, written at `position`, when it names a structure or enumeration that
+    /// was never declared, or is not given as many type arguments as it has type parameters.
+    fn check_type_exists(&mut self, data_type: &DataType, position: Position) {
+        self.mark_type_used(data_type);
+        let identifier = match data_type {
+            DataType::Identifier(identifier) | DataType::Applied(identifier, _) => identifier,
+            _ => return,
+        };
+        if !self.is_declared_type(identifier) && !builtin_types().contains(identifier) {
+            let suggestion = self.suggest_type(identifier);
+            self.diagnostics.report(
+                position,
+                SemanticError::UndeclaredType(identifier.clone(), suggestion),
+            );
+            return;
+        }
+        let arguments = match data_type {
+            DataType::Applied(_, arguments) => arguments.as_slice(),
+            _ => &[],
+        };
+        let expected = self.type_params(identifier).map_or(0, <[String]>::len);
+        if expected != arguments.len() {
+            self.diagnostics.report(
+                position,
+                SemanticError::TypeArgumentCount(identifier.clone(), expected, arguments.len()),
+            );
+        }
+        for argument in arguments {
+            self.check_type_exists(argument, position);
+        }
+    }
+
+    fn check_declared_type(&mut self, decl: &VarDecl, data_type: &DataType) {
+        self.check_type_exists(data_type, decl.position);
+        let var = TypeCell {
+            identifier: decl.identifier.clone(),
+            data_type: data_type.clone(),
+            mutable: decl.mutable,
+        };
+        if let Some(value) = &decl.value {
+            self.validate_declaration_expression(&var, value, decl.position);
+        }
+    }
+
+    /// Type of a declaration without one, taken from its value. A value of unknown type has
+    /// already been reported, and one of type `void` cannot be stored; either way the variable
+    /// gets [`unknown_type`] so its uses are not reported again.
+    fn infer_declared_type(&mut self, decl: &VarDecl) -> DataType {
+        let value = decl
+            .value
+            .as_ref()
+            .expect("a declaration without a type always has a value");
+        match self.expression_type(value) {
+            Some(DataType::Void) => {
+                self.diagnostics.report(
+                    decl.position,
+                    SemanticError::VoidInitialiser(decl.identifier.clone()),
+                );
+                unknown_type()
+            }
+            Some(data_type) => data_type,
+            None => unknown_type(),
+        }
+    }
+
+    pub(crate) fn enum_variants(&self, enumeration: &str) -> Option<&Vec<VariantCell>> {
+        self.valid_id_types
+            .iter()
+            .find_map(|valid_t| match valid_t {
+                IdentifierTypeCell::EnumType {
+                    identifier,
+                    variants,
+                } if identifier == enumeration => Some(variants),
+                _ => None,
+            })
+    }
+
+    /// Enumeration declaring the variant `identifier`, and the variant.
+    pub(crate) fn find_variant(&self, identifier: &str) -> Option<(&'a str, &'a VariantCell)> {
+        self.valid_id_types
+            .iter()
+            .find_map(|valid_t| match valid_t {
+                IdentifierTypeCell::EnumType {
+                    identifier: enumeration,
+                    variants,
+                } => variants
+                    .iter()
+                    .find(|variant| variant.identifier == identifier)
+                    .map(|variant| (enumeration.as_str(), variant)),
+                _ => None,
+            })
+    }
+
+    fn check_assignment(&mut self, assign: &Assign) {
+        if let Err(error) = self.validate_assignment(assign) {
+            self.diagnostics.report(assign.position, *error);
+        }
+        // A plain `=` stores a value in the variable, or in one of its fields, even when the
+        // value was invalid.
+        if let (Expr::Identifier(..) | Expr::Field { .. }, Operator::Assignment) =
+            (&assign.target, &assign.operator)
+        {
+            self.scopes
+                .mark_assigned(assign.target.root_identifier().unwrap_or_default());
+        }
+    }
+
+    /// Type of `target`, the variable `var` or a field in it, which is written without reading
+    /// the variable.
+    fn place_type(&mut self, target: &Expr, var: &TypeCell) -> Option<DataType> {
+        match target {
+            Expr::Identifier(..) => Some(var.data_type.clone()).filter(|t| !self.is_poisoned(t)),
+            Expr::Field {
+                base,
+                field,
+                position,
+            } => {
+                let base_type = self.place_type(base, var)?;
+                self.field_type(&base_type, field)
+                    .map_err(|error| self.diagnostics.report(*position, *error))
+                    .ok()
+            }
+            target => self.expression_type(target),
+        }
+    }
+
+    fn validate_assignment(&mut self, assign: &Assign) -> Result<(), Box<SemanticError>> {
+        let root = assign.target.root_identifier().unwrap_or_default();
+        if let Expr::Call { .. } = assign.target {
+            return Err(Box::from(SemanticError::InvalidAssignmentTarget(
+                root.to_string(),
+            )));
+        }
+        let var = match self.scopes.find(root) {
+            Some(var) if !var.mutable => {
+                return Err(Box::from(SemanticError::AssignmentToConstant(
+                    var.identifier.clone(),
+                )))
+            }
+            Some(var) => var.clone(),
+            None => {
+                if let Some(declared_at) = self.later_declaration(root, assign.position) {
+                    self.diagnostics.report_related(
+                        assign.position,
+                        SemanticError::UsedBeforeDeclaration(root.to_string()),
+                        declared_at,
+                    );
+                    return Ok(());
+                }
+                return Err(Box::from(SemanticError::UndeclaredIdentifier(
+                    root.to_string(),
+                    self.suggest_identifier(root),
+                )));
+            }
+        };
+        let target_type = match &assign.target {
+            // Writing to a variable or to one of its fields does not count as reading it, unless
+            // the operator combines the new value with the current one.
+            Expr::Identifier(..) => {
+                if assign.operator != Operator::Assignment && !self.scopes.is_assigned(root) {
+                    self.diagnostics.report(
+                        assign.position,
+                        SemanticError::UnassignedVariable(root.to_string()),
+                    );
+                    self.scopes.mark_assigned(root);
+                }
+                self.place_type(&assign.target, &var)
+            }
+            Expr::Field { .. } if assign.operator == Operator::Assignment => {
+                self.place_type(&assign.target, &var)
+            }
+            target => self.expression_type(target),
+        };
+        let value_type = self.expression_type(&assign.value);
+        let (Some(target_type), Some(value_type)) = (target_type, value_type) else {
+            return Ok(());
+        };
+        let target = TypeCell {
+            identifier: var.identifier,
+            data_type: target_type,
+            mutable: var.mutable,
+        };
+        // `+=` and `-=` only make sense on numbers, plus `+=` as string concatenation.
+        let compound_allowed = match assign.operator {
+            Operator::Assignment => true,
+            Operator::Increment => {
+                is_numeric(&target.data_type) || target.data_type == DataType::Str
+            }
+            _ => is_numeric(&target.data_type),
+        };
+        if !compound_allowed {
+            return Err(Box::from(SemanticError::UnsupportedOperation(
+                assign.operator.to_string(),
+                target.data_type,
+            )));
+        }
+        if self.check_int_range(&target.data_type, &assign.value, assign.position) {
+            return Ok(());
+        }
+        // `a += b` stores `a + b` in `a`, and `a -= b` stores `a - b`.
+        let stored_type = match assign.operator {
+            Operator::Increment => self.binary_type(&Operator::Sum, &target.data_type, &value_type),
+            Operator::Decrement => {
+                self.binary_type(&Operator::Subtraction, &target.data_type, &value_type)
+            }
+            _ => Some(value_type.clone()),
+        };
+        if !stored_type.is_some_and(|stored_type| widens_to(&stored_type, &target.data_type)) {
+            return Err(Box::from(SemanticError::IncompatibleTypes(
+                target,
+                TypeCell {
+                    identifier: assign
+                        .value
+                        .root_identifier()
+                        .unwrap_or_default()
+                        .to_string(),
+                    data_type: value_type,
+                    mutable: false,
+                },
+            )));
+        }
+        Ok(())
+    }
+
+    /// Global initialisers must be computable at compile time: literals, `const` globals declared
+    /// above, and operations over those.
+    fn validate_constant_expression(&self, value: &Expr) -> Result<(), Box<SemanticError>> {
+        match value {
+            Expr::Literal(..) => Ok(()),
+            Expr::Identifier(identifier, _) => match self.scopes.find(identifier) {
+                Some(var) if !var.mutable => Ok(()),
+                Some(_) => Err(Box::from(SemanticError::NonConstantExpression(
+                    identifier.clone(),
+                ))),
+                // Reported when the initialiser is type checked.
+                None => Ok(()),
+            },
+            Expr::Unary { operand, .. } | Expr::Cast { operand, .. } => {
+                self.validate_constant_expression(operand)
+            }
+            Expr::Binary { lhs, rhs, .. } => {
+                self.validate_constant_expression(lhs)?;
+                self.validate_constant_expression(rhs)
+            }
+            Expr::Call { .. } | Expr::Field { .. } | Expr::Index { .. } => {
+                Err(Box::from(SemanticError::NonConstantExpression(
+                    value.root_identifier().unwrap_or_default().to_string(),
+                )))
+            }
+        }
+    }
+
+    fn validate_declaration_expression(
+        &mut self,
+        var: &TypeCell,
+        value: &Expr,
+        position: Position,
+    ) {
+        // A bare name that is not a variable can only be one of the enumeration's variants.
+        if let (DataType::Identifier(enumeration), Expr::Identifier(expr_id, _)) =
+            (&var.data_type, value)
+        {
+            if self.enum_variants(enumeration).is_some() && self.scopes.find(expr_id).is_none() {
+                let carries_data = self.enum_variants(enumeration).is_some_and(|variants| {
+                    variants.iter().any(|variant| {
+                        variant.identifier == *expr_id && !variant.parameters().is_empty()
+                    })
+                });
+                match AST::validate_id_type_expr(self.valid_id_types, var, expr_id) {
+                    Err(error) => self.diagnostics.report(position, *error),
+                    Ok(()) if carries_data => self.diagnostics.report(
+                        position,
+                        SemanticError::VariantNeedsPayload(expr_id.clone()),
+                    ),
+                    Ok(()) => {}
+                }
+                return;
+            }
+        }
+        let value_type = self.expression_type(value);
+        if value_type.is_some() && self.check_int_range(&var.data_type, value, position) {
+            return;
+        }
+        match value_type {
+            Some(data_type)
+                if !self.is_poisoned(&var.data_type) && !widens_to(&data_type, &var.data_type) =>
+            {
+                self.diagnostics.report(
+                    position,
+                    SemanticError::IncompatibleTypes(
+                        var.to_owned(),
+                        TypeCell {
+                            identifier: value.root_identifier().unwrap_or_default().to_string(),
+                            data_type,
+                            mutable: false,
+                        },
+                    ),
+                );
+            }
+            _ => {}
+        }
     }
 }
@@ -2,13 +2,16 @@
 extern crate core;
 
 use front::lexer::reserved::Separator;
-use front::lexer::tokenization::tokenize;
+use front::lexer::tokenization::{locate_tokens, tokenize, Location};
 use front::lexer::tokens::Token;
 use front::parser::enumeration::Enumeration;
 use front::parser::function::Function;
-use front::parser::grammar::{Parser, ParsingRule};
+use front::parser::generics::Generics;
+use front::parser::grammar::{Parser, ParsingRule, SyntaxError, AST};
 use front::parser::program::Program;
 use front::parser::structure::Struct;
+use front::semantics::functions::Target;
+use front::semantics::lints::{Level, LintLevels};
 use std::path::Path;
 
 mod csv_output;
@@ -27,12 +30,65 @@ fn check_file(source_file: &Path) -> Result<(), String> {
     }
 }
 
+/// How strictly a program is judged.
+#[derive(Debug, Default)]
+pub struct Options {
+    pub lints: LintLevels,
+    pub target: Target,
+    /// Prints the memory layout of every structure and enumeration.
+    pub print_layouts: bool,
+    /// Prints the tables of types, functions and bindings built while type checking.
+    pub print_tables: bool,
+}
+
+/// Reads `[-W code | -A code | -D code | --deny-warnings | --lib | --print-layouts |
+/// --print-tables]... [file]`.
+fn parse_args(args: &[String]) -> Result<(Options, &Path), String> {
+    let mut options = Options::default();
+    let mut source_file = Path::new("input.glim");
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let level = match arg.as_str() {
+            "--deny-warnings" => {
+                options.lints.deny_warnings = true;
+                continue;
+            }
+            "--lib" => {
+                options.target = Target::Library;
+                continue;
+            }
+            "--print-layouts" => {
+                options.print_layouts = true;
+                continue;
+            }
+            "--print-tables" => {
+                options.print_tables = true;
+                continue;
+            }
+            "-W" => Level::Warn,
+            "-A" => Level::Allow,
+            "-D" => Level::Deny,
+            _ => {
+                source_file = Path::new(arg);
+                continue;
+            }
+        };
+        let code = args
+            .next()
+            .ok_or_else(|| format!("missing lint code after '{arg}'"))?;
+        options.lints.set(code, level)?;
+    }
+    Ok((options, source_file))
+}
+
 fn main() {
-    let args: Vec<String> = std::env::args().collect();
-    let source_file = if args.len() != 2 {
-        Path::new("input.glim")
-    } else {
-        Path::new(&args[1])
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let (options, source_file) = match parse_args(&args) {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            eprintln!("{err}");
+            std::process::exit(1);
+        }
     };
     match check_file(source_file) {
         Ok(_) => {
@@ -40,13 +96,13 @@ fn main() {
                 .unwrap()
                 .replace("\r\n", "\n");
 
-            match run_compiler(code) {
+            match run_compiler_with(code, &options) {
                 Ok(_) => {
                     println!("Compilation successful.");
                     std::process::exit(0);
                 }
                 Err(err) => {
-                    eprintln!("\nCompilation error: {err}");
+                    eprintln!("\n{err}");
                     std::process::exit(1);
                 }
             }
@@ -58,10 +114,16 @@ fn main() {
     }
 }
 
-fn run_compiler(code: &String) -> Result<(), String> {
-    let tokens = tokenize(code)
+fn run_compiler(code: &str) -> Result<(), String> {
+    run_compiler_with(code, &Options::default())
+}
+
+/// Splits `code` into the tokens the parser reads, with where each one starts.
+fn lex(code: &str) -> (Tokens, Vec<Location>) {
+    tokenize(code)
         .into_iter()
-        .filter(|token| {
+        .zip(locate_tokens(code))
+        .filter(|(token, _)| {
             !matches!(
                 token,
                 Token::Separator(Separator::WhiteSpace)
@@ -69,28 +131,103 @@ fn run_compiler(code: &String) -> Result<(), String> {
                     | Token::Comment(_)
             )
         })
-        .collect::<Tokens>();
+        .unzip()
+}
 
-    let ast = ParsingRule::parse_with_table(
-        &tokens,
+fn parse(tokens: &[Token]) -> Result<AST, SyntaxError> {
+    ParsingRule::parse_with_table(
+        tokens,
         &[
             Program::PARSING_TABLE,
             Function::PARSING_TABLE,
             Enumeration::PARSING_TABLE,
             Struct::PARSING_TABLE,
+            Generics::PARSING_TABLE,
         ]
         .concat(),
-    );
+    )
+}
+
+fn run_compiler_with(code: &str, options: &Options) -> Result<(), String> {
+    let (tokens, locations) = lex(code);
+    let ast = parse(&tokens);
 
     match ast {
         Ok(table_output) => {
             csv_output::lexical_csv_output(code, &tokens);
             csv_output::ast_csv_output(&table_output);
-            match table_output.type_check() {
-                Ok(_) => Ok(println!("Type checking passed.")),
-                Err(err) => Err(format!("\nCompilation error: {err:?}")),
+            let report = table_output.type_check(options.target);
+            if options.print_tables {
+                print!("{}", report.tables);
+            }
+            if options.print_layouts {
+                println!("Layouts: {{");
+                for layout in &report.layouts {
+                    println!("{layout}");
+                }
+                println!("}}\n");
+            }
+            // Diagnostics found at the end of the input point at the last token.
+            let locate = |position: usize| {
+                locations
+                    .get(position)
+                    .or(locations.last())
+                    .copied()
+                    .unwrap_or_default()
+            };
+            let mut errors: Vec<(usize, String)> = report
+                .errors
+                .iter()
+                .map(|diagnostic| {
+                    let location = locate(diagnostic.position);
+                    let message = format!(
+                        "Compilation error[{}] at {}:{}: {}",
+                        diagnostic.error.code(),
+                        location.line,
+                        location.column,
+                        diagnostic.error
+                    );
+                    let message = match diagnostic.related.map(locate) {
+                        Some(related) => format!(
+                            "{message}\n  note: {} {}:{}",
+                            diagnostic.error.related_note(),
+                            related.line,
+                            related.column
+                        ),
+                        None => message,
+                    };
+                    (diagnostic.position, message)
+                })
+                .collect();
+            for warning in &report.warnings {
+                let location = locate(warning.position);
+                let code = warning.warning.code();
+                match options.lints.level(&warning.warning, warning.level) {
+                    Level::Allow => {}
+                    Level::Warn => eprintln!(
+                        "Warning[{code}] at {}:{}: {}",
+                        location.line, location.column, warning.warning
+                    ),
+                    Level::Deny => errors.push((
+                        warning.position,
+                        format!(
+                            "Compilation error[{code}] at {}:{}: {}",
+                            location.line, location.column, warning.warning
+                        ),
+                    )),
+                }
+            }
+            if errors.is_empty() {
+                println!("Type checking passed.");
+                return Ok(());
             }
+            errors.sort_by_key(|(position, _)| *position);
+            Err(errors
+                .into_iter()
+                .map(|(_, message)| message)
+                .collect::<Vec<_>>()
+                .join("\n"))
         }
-        Err(err) => Err(format!("{err}")),
+        Err(err) => Err(format!("Compilation error: {err}")),
     }
 }
@@ -1,4 +1,5 @@
 #[cfg(test)]
+#[allow(clippy::module_inception)]
 mod tests {
     use crate::*;
 
@@ -11,4 +12,1748 @@ mod tests {
         "#;
         assert!(run_compiler(&String::from(input)).is_err());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_block_scope_shadowing() {
+        let input = r#"
+            func void main() {
+                let int a = 1;
+                {
+                    let boolean a = true;
+                    let boolean b = a;
+                }
+                let int c = a;
+            }
+        "#;
+        assert!(run_compiler(input).is_ok());
+    }
+
+    #[test]
+    fn test_block_scope_ends_with_block() {
+        let input = r#"
+            func void main() {
+                {
+                    let int a = 1;
+                }
+                let int c = a;
+            }
+        "#;
+        assert!(run_compiler(input).is_err());
+    }
+
+    #[test]
+    fn test_same_name_in_two_functions() {
+        let input = r#"
+            func void first() {
+                let int a = 1;
+            }
+            func void second() {
+                let boolean a = true;
+                let boolean b = a;
+            }
+
+            func void main() {}
+        "#;
+        assert!(run_compiler(input).is_ok());
+    }
+
+    #[test]
+    fn test_redeclaration_in_same_scope() {
+        let input = r#"
+            func void main(int a) {
+                let int a = 1;
+            }
+        "#;
+        assert!(run_compiler(input).is_err());
+    }
+
+    #[test]
+    fn test_global_visible_from_functions() {
+        let input = r#"
+            func void main() {
+                let int a = MAX;
+            }
+            const int MAX = 10;
+            let int counter = MAX;
+        "#;
+        assert!(run_compiler(input).is_ok());
+    }
+
+    #[test]
+    fn test_global_initialiser_must_be_constant() {
+        let input = r#"
+            let int counter = 1;
+            const int MAX = counter;
+
+            func void main() {}
+        "#;
+        assert!(run_compiler(input).is_err());
+    }
+
+    #[test]
+    fn test_assignment_to_const_global() {
+        let input = r#"
+            const int MAX = 10;
+            func void main() {
+                MAX = 3;
+            }
+        "#;
+        assert!(run_compiler(input).is_err());
+    }
+
+    #[test]
+    fn test_match_patterns() {
+        let input = r#"
+            enumeration Color { Red, Green, Blue }
+            func void main() {
+                let int n = 3;
+                match (n) {
+                    case 1..=9, 12 { }
+                    case _ { }
+                }
+                let Color c = Red;
+                match (c) {
+                    case Color::Red, Green { }
+                    case Blue { }
+                }
+            }
+        "#;
+        assert!(run_compiler(input).is_ok());
+    }
+
+    #[test]
+    fn test_match_pattern_type() {
+        let input = r#"
+            func void main() {
+                let int n = 3;
+                match (n) {
+                    case true { }
+                }
+            }
+        "#;
+        assert!(run_compiler(input).is_err());
+    }
+
+    #[test]
+    fn test_match_unreachable_pattern() {
+        let input = r#"
+            func void main() {
+                let int n = 3;
+                match (n) {
+                    case 1..=9 { }
+                    case 5 { }
+                }
+            }
+        "#;
+        assert!(run_compiler(input).is_err());
+    }
+
+    #[test]
+    fn test_match_same_value_of_other_type() {
+        let input = r#"
+func void main() {
+    let double d = 1.0;
+    match (d) {
+        case 1 { }
+        case 1.0 { }
+        default { }
+    }
+}
+        "#;
+        assert_eq!(
+            run_compiler(input).unwrap_err(),
+            "Compilation error[G0021] at 6:14: pattern '1' is matched more than once"
+        );
+    }
+
+    #[test]
+    fn test_match_exhaustive_enum() {
+        let input = r#"
+            enumeration Color { Red, Green }
+            func void main() {
+                let Color c = Red;
+                match (c) {
+                    case Red { }
+                }
+            }
+        "#;
+        assert!(run_compiler(input).is_err());
+    }
+
+    #[test]
+    fn test_match_exhaustive_with_default() {
+        let input = r#"
+            func void main() {
+                let boolean b = true;
+                match (b) {
+                    case true { }
+                    default { }
+                }
+            }
+        "#;
+        assert!(run_compiler(input).is_ok());
+    }
+
+    #[test]
+    fn test_function_calls() {
+        let input = r#"
+            func int add(int a, int b) {
+                return a;
+            }
+            func void main() {
+                let int x = add(1, 2);
+                let int y = add(x, add(3, 4));
+                add(x, y);
+            }
+        "#;
+        assert!(run_compiler(input).is_ok());
+    }
+
+    #[test]
+    fn test_function_call_arity() {
+        let input = r#"
+            func int add(int a, int b) {
+                return a;
+            }
+            func void main() {
+                let int x = add(1);
+            }
+        "#;
+        assert!(run_compiler(input).is_err());
+    }
+
+    #[test]
+    fn test_function_call_argument_type() {
+        let input = r#"
+            func int add(int a, int b) {
+                return a;
+            }
+            func void main() {
+                let int x = add(1, true);
+            }
+        "#;
+        assert!(run_compiler(input).is_err());
+    }
+
+    #[test]
+    fn test_duplicate_function() {
+        let input = r#"
+            func void main() { }
+            func void main() { }
+        "#;
+        assert!(run_compiler(input).is_err());
+    }
+
+    #[test]
+    fn test_return_on_all_paths() {
+        let input = r#"
+            func int sign(int a) {
+                if (a > 0) {
+                    return 1;
+                } else {
+                    match (a) {
+                        case 0 { return 0; }
+                        default { return 2; }
+                    }
+                }
+            }
+            func void nothing() {
+                return;
+            }
+
+            func void main() {}
+        "#;
+        assert!(run_compiler(input).is_ok());
+    }
+
+    #[test]
+    fn test_missing_return() {
+        let input = r#"
+            func int sign(int a) {
+                if (a) {
+                    return 1;
+                }
+            }
+
+            func void main() {}
+        "#;
+        assert!(run_compiler(input).is_err());
+    }
+
+    #[test]
+    fn test_return_type_mismatch() {
+        let input = r#"
+            func int main() {
+                return true;
+            }
+        "#;
+        assert!(run_compiler(input).is_err());
+    }
+
+    #[test]
+    fn test_return_value_in_void_function() {
+        let input = r#"
+            func void main() {
+                return 0;
+            }
+        "#;
+        assert!(run_compiler(input).is_err());
+    }
+
+    #[test]
+    fn test_assignments() {
+        let input = r#"
+            func void main() {
+                let int a = 1;
+                a = 2;
+                a += 3;
+                let string s = "text";
+                s += s;
+                s += 'c';
+                s = s + 'c';
+            }
+        "#;
+        assert!(run_compiler(input).is_ok());
+    }
+
+    #[test]
+    fn test_compound_assignment_result_type() {
+        let input = r#"
+func void main() {
+    let int count = 1;
+    count += 1.5;
+    let character letter = 'a';
+    letter += "b";
+}
+        "#;
+        // The sum is stored in the variable, so it must have the variable's type.
+        assert_eq!(
+            run_compiler(input).unwrap_err(),
+            "Compilation error[G0010] at 4:5: cannot assign 'float' to variable 'count' of type 'int'\n\
+             Compilation error[G0034] at 6:5: operator '+=' cannot be applied to 'character'"
+        );
+    }
+
+    #[test]
+    fn test_assignment_type_mismatch() {
+        let input = r#"
+            func void main() {
+                let int pedor = 1;
+                pedor = "string";
+            }
+        "#;
+        assert!(run_compiler(input).is_err());
+    }
+
+    #[test]
+    fn test_assignment_to_undeclared() {
+        let input = r#"
+            func void main() {
+                let int pedor = 1;
+                Pedor += 1;
+            }
+        "#;
+        assert!(run_compiler(input).is_err());
+    }
+
+    #[test]
+    fn test_compound_assignment_on_boolean() {
+        let input = r#"
+            func void main() {
+                let boolean b = true;
+                b -= false;
+            }
+        "#;
+        assert!(run_compiler(input).is_err());
+    }
+
+    #[test]
+    fn test_operator_types() {
+        let input = r#"
+            func void main() {
+                let int a = 1;
+                let int b = -(a + 2) * 3;
+                let boolean c = a < b && !(b == 4) || false;
+                let string s = "text" + 'c';
+            }
+        "#;
+        assert!(run_compiler(input).is_ok());
+    }
+
+    #[test]
+    fn test_mixed_operand_types() {
+        let input = r#"
+            func void main() {
+                let int a = 1 + true;
+            }
+        "#;
+        assert!(run_compiler(input).is_err());
+    }
+
+    #[test]
+    fn test_logical_operator_on_int() {
+        let input = r#"
+            func void main() {
+                let boolean b = 1 && true;
+            }
+        "#;
+        assert!(run_compiler(input).is_err());
+    }
+
+    #[test]
+    fn test_comparison_is_boolean() {
+        let input = r#"
+            func void main() {
+                let int a = 1;
+                let int b = a < 2;
+            }
+        "#;
+        assert!(run_compiler(input).is_err());
+    }
+
+    #[test]
+    fn test_string_concatenation() {
+        let input = r#"
+            func void main() {
+                let string a = "left";
+                let string b = a + "right";
+                let string c = 'x' + 'y';
+            }
+        "#;
+        assert!(run_compiler(input).is_err());
+    }
+
+    #[test]
+    fn test_implicit_numeric_widening() {
+        let input = r#"
+            func double scale(double factor) {
+                return factor * 2;
+            }
+
+            func void main() {
+                let long big = 1;
+                let double ratio = 1 + 0.5;
+                big += 2;
+                let double result = scale(ratio);
+            }
+        "#;
+        assert!(run_compiler(input).is_ok());
+    }
+
+    #[test]
+    fn test_lossy_numeric_conversions() {
+        let input = r#"
+func float shrink(long big) {
+    return big;
+}
+
+func void main() {
+    let long big = 1;
+    let float ratio = big;
+    let double precise = big;
+    let int small = 2;
+    let float rounded = small;
+    let double widened = small + 0.5;
+}
+        "#;
+        assert_eq!(
+            run_compiler(input).unwrap_err(),
+            "Compilation error[G0031] at 3:5: function 'shrink' returns 'float', found 'long'\n\
+             Compilation error[G0010] at 8:5: cannot assign 'long' to variable 'ratio' of type 'float'\n\
+             Compilation error[G0010] at 9:5: cannot assign 'long' to variable 'precise' of type 'double'\n\
+             Compilation error[G0010] at 11:5: cannot assign 'int' to variable 'rounded' of type 'float'"
+        );
+    }
+
+    #[test]
+    fn test_implicit_narrowing() {
+        let input = r#"
+            func void main() {
+                let long big = 1;
+                let int small = big;
+            }
+        "#;
+        assert!(run_compiler(input).is_err());
+    }
+
+    #[test]
+    fn test_explicit_casts() {
+        let input = r#"
+            enumeration Color { Red, Green }
+
+            func void main() {
+                let long big = 1;
+                let int small = big as int;
+                let int code = 'a' as int + Green as int;
+                let character letter = code as character;
+            }
+        "#;
+        assert!(run_compiler(input).is_ok());
+    }
+
+    #[test]
+    fn test_invalid_cast() {
+        let input = r#"
+            struct Point { int x, int y }
+
+            func void main(Point p) {
+                let int value = p as int;
+            }
+        "#;
+        assert!(run_compiler(input).is_err());
+    }
+
+    #[test]
+    fn test_boolean_conditions() {
+        let input = r#"
+            func void main() {
+                let int a = 1;
+                let boolean done = false;
+                if (a > 0 && !done) {
+                    a += 1;
+                } elif (a == 0) {
+                    a = 2;
+                }
+                while (!done) {
+                    done = true;
+                }
+                for (let int i = 0; i < 10; i += 1) {
+                    a += i;
+                }
+            }
+        "#;
+        assert!(run_compiler(input).is_ok());
+    }
+
+    #[test]
+    fn test_integer_condition() {
+        let input = r#"
+            func void main() {
+                let int a = 1;
+                while (a) {
+                    a -= 1;
+                }
+            }
+        "#;
+        assert!(run_compiler(input).is_err());
+    }
+
+    #[test]
+    fn test_assignment_in_condition() {
+        let input = r#"
+            func void main() {
+                let int a = 1;
+                if (a = 2) {
+                    a += 1;
+                }
+            }
+        "#;
+        assert!(run_compiler(input).is_err());
+    }
+
+    #[test]
+    fn test_string_for_condition() {
+        let input = r#"
+            func void main() {
+                for (let int i = 0; "text"; i += 1) { }
+            }
+        "#;
+        assert!(run_compiler(input).is_err());
+    }
+
+    #[test]
+    fn test_reports_every_error() {
+        let input = r#"
+            func void main() {
+                let int a = true;
+                let boolean b = 1 + 'c';
+                undeclared = 2;
+            }
+        "#;
+        let errors = run_compiler(input).unwrap_err();
+        assert_eq!(errors.matches("Compilation error").count(), 3);
+    }
+
+    #[test]
+    fn test_errors_sorted_by_position() {
+        let input = r#"
+            func void main() {
+                let int a = missing;
+            }
+
+            let int global = true;
+        "#;
+        let errors = run_compiler(input).unwrap_err();
+        let undeclared = errors.find("cannot find 'missing'").unwrap();
+        let incompatible = errors.find("cannot assign 'boolean'").unwrap();
+        assert!(undeclared < incompatible);
+    }
+
+    #[test]
+    fn test_poisoned_types_reported_once() {
+        let input = r#"
+            func void main() {
+                let Missing m = 1;
+                let int value = m.field + 1;
+                let int other = unknown * 2;
+                let int last = other + value;
+            }
+        "#;
+        let errors = run_compiler(input).unwrap_err();
+        assert_eq!(errors.matches("Compilation error").count(), 2);
+    }
+
+    #[test]
+    fn test_error_message() {
+        let input = r#"
+func void main() {
+    let int pedor = 1;
+    pedor = "string";
+}
+        "#;
+        assert_eq!(
+            run_compiler(input).unwrap_err(),
+            "Compilation error[G0010] at 4:5: cannot assign 'string' to variable 'pedor' of type 'int'"
+        );
+    }
+
+    #[test]
+    fn test_duplicate_and_undefined_variables() {
+        let input = r#"
+            func void main() {
+                let int a = 1;
+                let int a = 2;
+                let int b = b + 1;
+            }
+        "#;
+        let errors = run_compiler(input).unwrap_err();
+        assert!(errors.contains("'a' is declared more than once"));
+        assert!(errors.contains("variable 'b' is used in its own initialiser"));
+    }
+
+    #[test]
+    fn test_division_by_zero() {
+        let input = r#"
+            func int main() {
+                let int a = 10;
+                return a / 0;
+            }
+        "#;
+        assert!(run_compiler(input)
+            .unwrap_err()
+            .contains("division by zero"));
+    }
+
+    #[test]
+    fn test_unreachable_code() {
+        let input = r#"
+            func int main() {
+                return 0;
+                let int a = 1;
+            }
+        "#;
+        assert!(run_compiler(input).is_ok());
+        let mut options = Options::default();
+        options.lints.set("G0106", Level::Deny).unwrap();
+        assert_eq!(
+            run_compiler_with(input, &options).unwrap_err(),
+            "Compilation error[G0106] at 4:17: unreachable code after 'return'"
+        );
+    }
+
+    #[test]
+    fn test_redeclaration_points_at_original() {
+        let input = r#"
+func void main() {
+    let int a = 1;
+    let int a = 2;
+}
+        "#;
+        assert_eq!(
+            run_compiler(input).unwrap_err(),
+            "Compilation error[G0015] at 4:5: 'a' is declared more than once\n  note: first declared at 3:5"
+        );
+    }
+
+    #[test]
+    fn test_redeclared_fields_and_variants() {
+        let input = r#"
+            struct Point { int x, int y, float x }
+            enumeration Color { Red, Green, Red }
+
+            func void main() {}
+        "#;
+        let errors = run_compiler(input).unwrap_err();
+        assert!(errors.contains("'x' is declared more than once"));
+        assert!(errors.contains("'Red' is declared more than once"));
+        assert_eq!(errors.matches("note: first declared at").count(), 2);
+    }
+
+    #[test]
+    fn test_redeclared_types() {
+        let input = r#"
+            struct Point { int x }
+            enumeration Point { Origin }
+            enumeration Shape { Circle, Point }
+
+            func void main() {}
+        "#;
+        let errors = run_compiler(input).unwrap_err();
+        assert_eq!(
+            errors.matches("'Point' is declared more than once").count(),
+            2
+        );
+
+        // Only the declaration kept is warned about when unused.
+        let (tokens, _) = lex(input);
+        let report = parse(&tokens)
+            .unwrap_or_else(|err| panic!("{err}"))
+            .type_check(Target::Executable);
+        let warnings: Vec<String> = report
+            .warnings
+            .iter()
+            .map(|warning| warning.warning.to_string())
+            .collect();
+        assert_eq!(
+            warnings,
+            ["type 'Point' is never used", "type 'Shape' is never used"]
+        );
+    }
+
+    #[test]
+    fn test_case_only_suggestion() {
+        let input = r#"
+            func void main() {
+                let int pedor = 1;
+                Pedor += 1;
+            }
+        "#;
+        assert!(run_compiler(input).unwrap_err().contains(
+            "cannot find 'Pedor' in this scope; did you mean 'pedor'? names are case-sensitive"
+        ));
+    }
+
+    #[test]
+    fn test_misspelt_name_suggestions() {
+        let input = r#"
+            struct Point { int x, int y }
+
+            func void main() {
+                let int counter = 1;
+                let int next = countr + 1;
+                let Pont origin = 0;
+                let Point p = origin;
+                let int z = p.z;
+            }
+        "#;
+        let errors = run_compiler(input).unwrap_err();
+        assert!(errors.contains("cannot find 'countr' in this scope; did you mean 'counter'?"));
+        assert!(errors.contains("undeclared type 'Pont'; did you mean 'Point'?"));
+        assert!(errors.contains("structure 'Point' has no field 'z'; did you mean 'x'?"));
+    }
+
+    #[test]
+    fn test_no_suggestion_for_unrelated_name() {
+        let input = r#"
+            func void main() {
+                let int counter = 1;
+                let int next = velocity + counter;
+            }
+        "#;
+        assert_eq!(
+            run_compiler(input)
+                .unwrap_err()
+                .lines()
+                .next()
+                .unwrap()
+                .trim_end(),
+            "Compilation error[G0009] at 4:32: cannot find 'velocity' in this scope"
+        );
+    }
+
+    #[test]
+    fn test_inferred_declarations() {
+        let input = r#"
+            enumeration Color { Red, Green }
+            struct Point { int x, int y }
+
+            const LIMIT = 10;
+
+            func double scale(double factor) {
+                return factor * 2;
+            }
+
+            func void describe(Point p) {
+                let count = LIMIT + 1;
+                let ratio = scale(count);
+                let color = Green;
+                let origin = p;
+                let int x = origin.x;
+                let long total = count;
+                let double half = ratio / 2;
+                let Color other = color;
+                for (let i = 0; i < count; i += 1) { }
+            }
+
+            func void main() {}
+        "#;
+        assert!(run_compiler(input).is_ok());
+    }
+
+    #[test]
+    fn test_inferred_type_is_enforced() {
+        let input = r#"
+            func void main() {
+                let flag = true;
+                flag = 1;
+                const name = "text";
+                let int length = name;
+            }
+        "#;
+        let errors = run_compiler(input).unwrap_err();
+        assert!(errors.contains("cannot assign 'int' to variable 'flag' of type 'boolean'"));
+        assert!(errors.contains("cannot assign 'string' to variable 'length' of type 'int'"));
+    }
+
+    #[test]
+    fn test_inference_from_void_or_invalid_value() {
+        let input = r#"
+            func void nothing() { }
+
+            func void main() {
+                let empty = nothing();
+                let broken = missing + 1;
+                let int a = empty + broken;
+            }
+        "#;
+        let errors = run_compiler(input).unwrap_err();
+        assert!(errors.contains("cannot infer a type for 'empty' from a 'void' value"));
+        assert_eq!(errors.matches("Compilation error").count(), 2);
+    }
+
+    #[test]
+    fn test_use_before_declaration() {
+        let input = r#"
+func void main() {
+    let int a = b + 1;
+    let int b = 2;
+}
+        "#;
+        assert_eq!(
+            run_compiler(input).unwrap_err(),
+            "Compilation error[G0047] at 3:17: variable 'b' is used before its declaration\n  note: declared at 4:5"
+        );
+    }
+
+    #[test]
+    fn test_definite_assignment() {
+        let input = r#"
+            enumeration Color { Red, Green }
+
+            func int pick(Color c, boolean flag) {
+                let int a;
+                if (flag) {
+                    a = 1;
+                } elif (!flag) {
+                    a = 2;
+                } else {
+                    return 0;
+                }
+                let int b;
+                match (c) {
+                    case Red { b = a; }
+                    case Green { b = 2; }
+                }
+                let int total;
+                total = a + b;
+                for (let int i = 0; i < total; i += 1) {
+                    let int step;
+                    step = i;
+                    total += step;
+                }
+                return total;
+            }
+
+            func void main() {}
+        "#;
+        assert!(run_compiler(input).is_ok());
+    }
+
+    #[test]
+    fn test_exhaustive_match_returning_in_every_case() {
+        let input = r#"
+            enumeration Color { Red, Green }
+
+            func int pick(Color c, boolean flag) {
+                let int fallback;
+                if (flag) {
+                    match (c) {
+                        case Red { return 1; }
+                        case Green { return 2; }
+                    }
+                } else {
+                    fallback = 3;
+                }
+                return fallback;
+            }
+
+            func void main() {}
+        "#;
+        assert!(run_compiler(input).is_ok());
+    }
+
+    #[test]
+    fn test_read_of_unassigned_variable() {
+        let input = r#"
+            func int main(boolean flag) {
+                let int a;
+                let int b;
+                if (flag) {
+                    a = 1;
+                    b = 1;
+                } else {
+                    b = 2;
+                }
+                let int c;
+                while (flag) {
+                    c = 3;
+                }
+                c += 1;
+                return a + b;
+            }
+        "#;
+        let errors = run_compiler(input).unwrap_err();
+        assert!(
+            errors.contains("variable 'a' is read before a value is assigned to it on every path")
+        );
+        assert!(
+            errors.contains("variable 'c' is read before a value is assigned to it on every path")
+        );
+        assert!(!errors.contains("variable 'b'"));
+    }
+
+    #[test]
+    fn test_assignment_before_loop_jumps() {
+        let input = r#"
+func int count(boolean flag) {
+    let int total = 0;
+    while (flag) {
+        let int x;
+        if (flag) {
+            x = 1;
+        } else {
+            break;
+        }
+        let int y = x;
+        total += y;
+    }
+    let int z;
+    while (true) {
+        z = 1;
+        break;
+    }
+    let int w = z;
+    return total + w;
+}
+
+func void main() {}
+        "#;
+        // A branch leaving the loop never reaches the code after it, and a loop that never
+        // stops on its own is left only at a `break`, after what was assigned before it.
+        assert!(run_compiler(input).is_ok());
+
+        let input = r#"
+func int count(boolean flag) {
+    let int z;
+    while (true) {
+        if (flag) {
+            break;
+        }
+        z = 1;
+        break;
+    }
+    return z;
+}
+
+func void main() {}
+        "#;
+        assert_eq!(
+            run_compiler(input).unwrap_err(),
+            "Compilation error[G0046] at 11:12: variable 'z' is read before a value is assigned to it on every path"
+        );
+    }
+
+    #[test]
+    fn test_field_assignment_to_unassigned_variable() {
+        let input = r#"
+struct Point { int x, int y }
+struct Line { Point a, Point b }
+
+func int length() {
+    let Line l;
+    l.a.x = 1;
+    let Point p;
+    p.y += 1;
+    return l.a.x + p.y;
+}
+
+func void main() {}
+        "#;
+        // Storing into a field writes to the variable, but `+=` reads the field first.
+        assert_eq!(
+            run_compiler(input).unwrap_err(),
+            "Compilation error[G0046] at 9:5: variable 'p' is read before a value is assigned to it on every path"
+        );
+    }
+
+    #[test]
+    fn test_constant_without_value() {
+        let input = r#"
+            let int counter;
+            func void main() {
+                const int LIMIT;
+            }
+        "#;
+        let errors = run_compiler(input).unwrap_err();
+        assert!(errors.contains("'counter' must be given a value where it is declared"));
+        assert!(errors.contains("'LIMIT' must be given a value where it is declared"));
+    }
+
+    #[test]
+    fn test_warnings_do_not_fail_compilation() {
+        let input = r#"
+            struct Unused { int x }
+
+            func int helper(int value, int ignored) {
+                return value;
+            }
+
+            func void main() {
+                let int a = helper(1, 2);
+            }
+        "#;
+        assert!(run_compiler(input).is_ok());
+    }
+
+    #[test]
+    fn test_unused_warnings() {
+        let input = r#"
+            struct Unused { int x }
+            enumeration Color { Red, Green }
+            const int LIMIT = 10;
+
+            func int helper(int value, int ignored) {
+                return value;
+            }
+
+            func int countdown(int n) {
+                return countdown(n - 1);
+            }
+
+            func void main() {
+                let int a = helper(1, 2);
+                let Color c = Red;
+                let int b = a + 1 + c as int;
+            }
+        "#;
+        let mut options = Options::default();
+        options.lints.deny_warnings = true;
+        let errors = run_compiler_with(input, &options).unwrap_err();
+        assert!(errors.contains("Compilation error[G0104] at 2:13: type 'Unused' is never used"));
+        assert!(errors.contains("variable 'LIMIT' is never used"));
+        assert!(errors.contains("parameter 'ignored' is never used"));
+        assert!(errors.contains("function 'countdown' is never called"));
+        assert!(errors.contains("variable 'b' is never used"));
+        assert_eq!(errors.matches("Compilation error").count(), 5);
+    }
+
+    #[test]
+    fn test_underscore_silences_unused_warnings() {
+        let input = r#"
+            struct _Reserved { int x }
+            const int _LIMIT = 10;
+
+            func int _helper(int value, int _ignored) {
+                let int _scratch = value;
+                return value;
+            }
+
+            func void main() { }
+        "#;
+        let mut options = Options::default();
+        options.lints.deny_warnings = true;
+        assert!(run_compiler_with(input, &options).is_ok());
+    }
+
+    #[test]
+    fn test_lint_attributes() {
+        let input = r#"
+            #[allow(G0102)]
+            func int first(int value, int ignored) {
+                return value;
+            }
+
+            #[deny(G0101)]
+            func void main() {
+                let int a = first(1, 2);
+            }
+        "#;
+        let errors = run_compiler(input).unwrap_err();
+        assert!(errors.contains("Compilation error[G0101]"));
+        assert!(errors.contains("variable 'a' is never used"));
+        assert_eq!(errors.matches("Compilation error").count(), 1);
+    }
+
+    #[test]
+    fn test_command_line_lint_levels() {
+        let input = r#"
+            #[warn(G0101)]
+            func int helper(int value, int ignored) {
+                let int unused = 0;
+                return value;
+            }
+
+            func void main() { }
+        "#;
+        let args: Vec<String> = ["-A", "G0102", "-D", "G0103", "-D", "G0101", "main.glim"]
+            .map(String::from)
+            .to_vec();
+        let (options, source_file) = parse_args(&args).unwrap();
+        assert_eq!(source_file, Path::new("main.glim"));
+        let errors = run_compiler_with(input, &options).unwrap_err();
+        assert!(errors.contains("function 'helper' is never called"));
+        assert!(!errors.contains("parameter 'ignored'"));
+        // The function's own attribute wins over the command line.
+        assert!(!errors.contains("variable 'unused'"));
+    }
+
+    #[test]
+    fn test_unknown_lints() {
+        let args: Vec<String> = ["-W", "G9999"].map(String::from).to_vec();
+        assert_eq!(parse_args(&args).unwrap_err(), "unknown lint 'G9999'");
+        let input = r#"
+            #[ignore(G0101)]
+            #[allow(G0101, G0199)]
+            func void main() { }
+        "#;
+        let errors = run_compiler(input).unwrap_err();
+        assert!(errors.contains("unknown attribute 'ignore', expected 'allow', 'warn' or 'deny'"));
+        assert!(errors.contains("Compilation error[G0049] at 3:28: unknown lint 'G0199'"));
+    }
+
+    #[test]
+    fn test_unreachable_after_jumps() {
+        let input = r#"
+func int main() {
+    let int i = 0;
+    while (i < 10) {
+        i += 1;
+        if (i == 5) {
+            continue;
+            i += 2;
+        }
+        break;
+        i = 0;
+    }
+    if (i > 0) {
+        return 1;
+    } else {
+        return 2;
+    }
+    i = 3;
+}
+        "#;
+        let mut options = Options::default();
+        options.lints.deny_warnings = true;
+        let errors = run_compiler_with(input, &options).unwrap_err();
+        assert_eq!(
+            errors,
+            "Compilation error[G0106] at 8:13: unreachable code after 'continue'\n\
+             Compilation error[G0106] at 11:9: unreachable code after 'break'\n\
+             Compilation error[G0106] at 18:5: unreachable code"
+        );
+    }
+
+    #[test]
+    fn test_constant_conditions() {
+        let input = r#"
+func int main() {
+    let int i = 0;
+    if (1 + 1 > 3) {
+        i = 1;
+    } elif (!false && i == 0) {
+        i = 2;
+    }
+    while (true) {
+        if (i > 5) {
+            return i;
+        }
+        i += 1;
+    }
+}
+        "#;
+        let mut options = Options::default();
+        options.lints.deny_warnings = true;
+        let errors = run_compiler_with(input, &options).unwrap_err();
+        assert!(errors.contains("Compilation error[G0107] at 4:15: condition is always 'false'"));
+        assert!(errors.contains("Compilation error[G0107] at 9:12: condition is always 'true'"));
+        // The loop only ends by returning, so no path runs off the end of `main`.
+        assert_eq!(errors.matches("Compilation error").count(), 2);
+    }
+
+    #[test]
+    fn test_jump_outside_loop() {
+        let input = r#"
+func void main() {
+    for (let int i = 0; i < 3; i += 1) {
+        continue;
+    }
+    break;
+}
+        "#;
+        assert_eq!(
+            run_compiler(input).unwrap_err(),
+            "Compilation error[G0050] at 6:5: 'break' outside of a loop"
+        );
+    }
+
+    #[test]
+    fn test_constant_division_by_zero() {
+        let input = r#"
+func void main() {
+    let int a = 10;
+    let int b = a / (2 - 2);
+    let double c = 1.5 / 0.0;
+    let int d = 6 / (3 - 1);
+}
+        "#;
+        let errors = run_compiler(input).unwrap_err();
+        assert!(errors.contains("Compilation error[G0005] at 4:19: division by zero"));
+        assert!(errors.contains("Compilation error[G0005] at 5:24: division by zero"));
+        assert_eq!(errors.matches("Compilation error").count(), 2);
+    }
+
+    #[test]
+    fn test_constant_overflow() {
+        let input = r#"
+const int MAX = 2147483647 + 1;
+const long BIG = 9223372036854775807 * 2;
+const int NESTED = (65536 * 65536) + 1;
+const long WIDE = 65536 * 65536;
+
+func void main() {
+    let long sum = MAX + BIG + NESTED + WIDE;
+}
+        "#;
+        let errors = run_compiler(input).unwrap_err();
+        assert!(errors
+            .contains("Compilation error[G0051] at 2:28: constant expression overflows 'int'"));
+        assert!(errors
+            .contains("Compilation error[G0051] at 3:38: constant expression overflows 'long'"));
+        // Reported once, where the overflow happens, not again for the enclosing expression.
+        assert!(errors
+            .contains("Compilation error[G0051] at 4:27: constant expression overflows 'int'"));
+        assert!(errors
+            .contains("Compilation error[G0051] at 5:25: constant expression overflows 'int'"));
+        assert_eq!(errors.matches("Compilation error").count(), 4);
+    }
+
+    #[test]
+    fn test_int_literal_range() {
+        let input = r#"
+func void main() {
+    let int min = -2147483648;
+    let int max = 2147483647;
+    let int big = 3000000000;
+    let int small = 0;
+    small = -2147483649;
+    let long fine = 3000000000;
+    let int sum = min + max + big + small + fine as int;
+}
+        "#;
+        let errors = run_compiler(input).unwrap_err();
+        assert!(errors.contains(
+            "Compilation error[G0052] at 5:5: constant '3000000000' does not fit in 'int'"
+        ));
+        assert!(errors.contains(
+            "Compilation error[G0052] at 7:5: constant '-2147483649' does not fit in 'int'"
+        ));
+        assert_eq!(errors.matches("Compilation error").count(), 2);
+    }
+
+    #[test]
+    fn test_entry_point_signature() {
+        let valid = r#"
+func int main() {
+    return 0;
+}
+        "#;
+        assert!(run_compiler(valid).is_ok());
+        let invalid = r#"
+func boolean main(int argc) {
+    return argc > 0;
+}
+        "#;
+        assert_eq!(
+            run_compiler(invalid).unwrap_err(),
+            "Compilation error[G0054] at 2:1: 'main' must be declared as 'func int main()' or 'func void main()'"
+        );
+    }
+
+    #[test]
+    fn test_missing_or_duplicate_entry_point() {
+        let missing = r#"
+func int helper() {
+    return 0;
+}
+        "#;
+        assert!(run_compiler(missing)
+            .unwrap_err()
+            .starts_with("Compilation error[G0053] at 2:1: no 'main' function"));
+        let duplicate = r#"
+func void main() {}
+
+func void main() {}
+        "#;
+        assert_eq!(
+            run_compiler(duplicate).unwrap_err(),
+            "Compilation error[G0055] at 4:1: a program has a single entry point, but 'main' is declared again\n  note: first declared at 2:1"
+        );
+    }
+
+    #[test]
+    fn test_library_has_no_entry_point() {
+        let input = r#"
+func int helper() {
+    return 0;
+}
+
+func int main(int argc) {
+    return argc;
+}
+        "#;
+        let (options, _) = parse_args(&["--lib".to_string()]).unwrap();
+        assert!(run_compiler_with(input, &options).is_ok());
+    }
+
+    #[test]
+    fn test_library_functions_are_not_unused() {
+        let input = r#"
+func int helper(int value) {
+    return value;
+}
+
+func int twice(int value) {
+    return helper(value) * 2;
+}
+        "#;
+        // Every function of a library may be called by the programs using it.
+        let args = ["--lib", "-D", "G0103"].map(String::from);
+        let (options, _) = parse_args(&args).unwrap();
+        assert!(run_compiler_with(input, &options).is_ok());
+    }
+
+    #[test]
+    fn test_recursive_structs() {
+        let input = r#"
+struct Node { int value, Node next }
+struct A { B b, boolean flag }
+struct B { string name, C c }
+struct C { A a }
+struct Wrapper { A inner }
+
+func void main() {}
+        "#;
+        assert_eq!(
+            run_compiler(input).unwrap_err(),
+            "Compilation error[G0056] at 2:26: struct 'Node' contains itself by value and would have infinite size: Node.next -> Node\n\
+             Compilation error[G0056] at 3:12: struct 'A' contains itself by value and would have infinite size: A.b -> B.c -> C.a -> A"
+        );
+    }
+
+    #[test]
+    fn test_struct_field_of_undeclared_type() {
+        let input = r#"
+struct Point { int x, int y }
+struct Segment { Pointt from, Point to }
+
+func void main() {}
+        "#;
+        assert_eq!(
+            run_compiler(input).unwrap_err(),
+            "Compilation error[G0008] at 3:18: undeclared type 'Pointt'; did you mean 'Point'?"
+        );
+    }
+
+    #[test]
+    fn test_struct_layouts() {
+        let input = r#"
+struct Point { boolean visible, double x, int y, character tag }
+struct Line { Point from, Point to, boolean dashed }
+struct Empty { }
+enumeration Color { Red, Green }
+
+func void main() {}
+        "#;
+        let (tokens, _) = lex(input);
+        let layouts: Vec<String> = parse(&tokens)
+            .unwrap_or_else(|err| panic!("{err}"))
+            .type_check(Target::Executable)
+            .layouts
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            layouts,
+            [
+                "struct Point: size 24, align 8\n  visible: boolean at offset 0\n  x: double at offset 8\n  y: int at offset 16\n  tag: character at offset 20",
+                "struct Line: size 56, align 8\n  from: Point at offset 0\n  to: Point at offset 24\n  dashed: boolean at offset 48",
+                "struct Empty: size 0, align 1",
+                "enumeration Color: size 1, align 1",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_user_types_as_parameters_and_returns() {
+        let input = r#"
+struct Point { int x, int y }
+enumeration Color { Red, Green }
+
+func Point shift(Point p, int by) {
+    let Point moved = p;
+    moved.x = p.x + by;
+    return moved;
+}
+
+func Color pick(boolean red) {
+    if (red) {
+        return Red;
+    }
+    return Green;
+}
+
+func int weigh(Point start) {
+    let Point end = shift(shift(start, 1), 2);
+    let int y = shift(end, 1).y;
+    let Color color = pick(y > start.x);
+    return y + color as int;
+}
+        "#;
+        let (options, _) = parse_args(&["--lib".to_string()]).unwrap();
+        assert!(run_compiler_with(input, &options).is_ok());
+    }
+
+    #[test]
+    fn test_user_types_in_signatures_are_checked() {
+        let input = r#"
+struct Point { int x, int y }
+enumeration Color { Red, Green }
+
+func Shape broken(Pointt p, Color c) {
+    return p;
+}
+
+func Point paint(Point p, Color c) {
+    return c;
+}
+
+func void use(Point p) {
+    let int value = broken(p, Red);
+    let Point painted = paint(Red, Green);
+}
+        "#;
+        let (options, _) = parse_args(&["--lib".to_string()]).unwrap();
+        assert_eq!(
+            run_compiler_with(input, &options).unwrap_err(),
+            "Compilation error[G0008] at 5:6: undeclared type 'Shape'\n\
+             Compilation error[G0008] at 5:19: undeclared type 'Pointt'; did you mean 'Point'?\n\
+             Compilation error[G0031] at 10:5: function 'paint' returns 'Point', found 'Color'\n\
+             Compilation error[G0027] at 15:31: argument 'p' of 'paint' expects 'Point', found 'Color'"
+        );
+    }
+
+    #[test]
+    fn test_enum_discriminants() {
+        let input = r#"
+enumeration Status { Ok = 200, NotFound = 404, Teapot = 418, Missing = 404, Next }
+enumeration Level { Low = -1, Mid, High = 0, Broken = 1.5 }
+
+func int code(Status status, Level level) {
+    return status as int + level as int;
+}
+        "#;
+        let (options, _) = parse_args(&["--lib".to_string()]).unwrap();
+        assert_eq!(
+            run_compiler_with(input, &options).unwrap_err(),
+            "Compilation error[G0058] at 2:62: variant 'Missing' has discriminant 404, which 'NotFound' already uses\n  note: first used at 2:32\n\
+             Compilation error[G0058] at 3:36: variant 'High' has discriminant 0, which 'Mid' already uses\n  note: first used at 3:31\n\
+             Compilation error[G0057] at 3:46: discriminant of variant 'Broken' must be an integer constant"
+        );
+    }
+
+    #[test]
+    fn test_enum_payloads() {
+        let input = r#"
+enumeration Shape { Circle(double), Rect { double w, double h }, Empty }
+
+func double area(Shape shape) {
+    match (shape) {
+        case Circle(r) { return r * r * 3.14; }
+        case Rect(h, w) { return w * h; }
+        case Empty { return 0.0; }
+    }
+}
+
+func Shape square(double side) {
+    return Rect(side, side);
+}
+
+func boolean round(Shape shape) {
+    match (shape) {
+        case Circle { return true; }
+        case Rect(_, _) { return false; }
+        default { return false; }
+    }
+}
+
+func void main() {
+    let Shape circle = Circle(2);
+    let double total = area(circle) + area(square(1.5)) + area(Empty);
+    let boolean both = round(circle) && round(square(total));
+}
+        "#;
+        assert!(run_compiler(input).is_ok());
+
+        let (tokens, _) = lex(input);
+        let layouts: Vec<String> = parse(&tokens)
+            .unwrap_or_else(|err| panic!("{err}"))
+            .type_check(Target::Executable)
+            .layouts
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            layouts,
+            ["enumeration Shape: size 24, align 8\n  Circle.1: double at offset 8\n  Rect.w: double at offset 8\n  Rect.h: double at offset 16"]
+        );
+    }
+
+    #[test]
+    fn test_rejected_pattern_still_covers_and_binds() {
+        let input = r#"
+enumeration Wrapped { Value(int) }
+
+func int unwrap(Wrapped wrapped) {
+    match (wrapped) {
+        case Value(a, b) {
+            return a + b;
+        }
+    }
+}
+        "#;
+        let (options, _) = parse_args(&["--lib".to_string()]).unwrap();
+        assert_eq!(
+            run_compiler_with(input, &options).unwrap_err(),
+            "Compilation error[G0059] at 6:14: pattern 'Value(a, b)' binds 2 value(s) but its variant carries 1"
+        );
+    }
+
+    #[test]
+    fn test_enum_payload_errors() {
+        let input = r#"
+enumeration Shape { Circle(double), Rect { double w, double h }, Empty }
+enumeration List { Nil, Cons(int, List) }
+
+func int classify(Shape shape) {
+    match (shape) {
+        case Circle(r, extra) { }
+        case Rect(w, depth) { }
+        case Empty(x) { }
+        case Circle, Rect(w) { }
+        default { }
+    }
+    let Shape bare = Circle;
+    let Shape wrong = Circle("big");
+    let Shape unit = Empty(1);
+    return 0;
+}
+        "#;
+        let (options, _) = parse_args(&["--lib".to_string()]).unwrap();
+        assert_eq!(
+            run_compiler_with(input, &options).unwrap_err(),
+            "Compilation error[G0056] at 3:25: enumeration 'List' contains itself by value and would have infinite size: List.Cons.2 -> List\n\
+             Compilation error[G0059] at 7:14: pattern 'Circle(r, extra)' binds 2 value(s) but its variant carries 1\n\
+             Compilation error[G0038] at 8:14: structure 'Shape::Rect' has no field 'depth'\n\
+             Compilation error[G0059] at 9:14: pattern 'Empty(x)' binds 1 value(s) but its variant carries 0\n\
+             Compilation error[G0021] at 10:14: pattern 'Circle' is matched more than once\n\
+             Compilation error[G0060] at 10:22: pattern 'Rect(w)' binds names, so it cannot share a case with other patterns\n\
+             Compilation error[G0021] at 10:22: pattern 'Rect(w)' is matched more than once\n\
+             Compilation error[G0061] at 13:5: variant 'Circle' carries data and must be constructed as 'Circle(...)'\n\
+             Compilation error[G0027] at 14:30: argument '1' of 'Circle' expects 'double', found 'string'\n\
+             Compilation error[G0025] at 15:27: 'Empty' is not a function"
+        );
+    }
+
+    #[test]
+    fn test_generic_structs_and_functions() {
+        let input = r#"
+struct Pair<T> { T a, T b }
+struct Labelled<K, V> { K key, Pair<V> values }
+
+func T max<T>(T a, T b) {
+    if (a > b) {
+        return a;
+    }
+    return b;
+}
+
+func T first<T>(Pair<T> pair) {
+    return pair.a;
+}
+
+func double widest(Pair<int> ints, Labelled<character, double> doubles, Pair<Pair<int>> nested) {
+    let int top = max(ints.a, nested.b.a);
+    let double larger = max(first(doubles.values), top);
+    let character key = max(doubles.key, 'a');
+    return larger;
+}
+        "#;
+        let (options, _) = parse_args(&["--lib".to_string()]).unwrap();
+        assert!(run_compiler_with(input, &options).is_ok());
+
+        let (tokens, _) = lex(input);
+        let report = parse(&tokens)
+            .unwrap_or_else(|err| panic!("{err}"))
+            .type_check(Target::Library);
+        assert_eq!(
+            report.instances,
+            ["max<int>", "first<double>", "max<double>", "max<character>"]
+        );
+        let layouts: Vec<String> = report.layouts.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            layouts,
+            [
+                "struct Pair<int>: size 8, align 4\n  a: int at offset 0\n  b: int at offset 4",
+                "struct Pair<double>: size 16, align 8\n  a: double at offset 0\n  b: double at offset 8",
+                "struct Labelled<character, double>: size 24, align 8\n  key: character at offset 0\n  values: Pair<double> at offset 8",
+                "struct Pair<Pair<int>>: size 16, align 4\n  a: Pair<int> at offset 0\n  b: Pair<int> at offset 8",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_generic_struct_returned() {
+        let input = r#"
+struct Pair<T> { T a, T b }
+
+func Pair<T> make<T>(T x) {
+    let Pair<T> pair;
+    pair.a = x;
+    pair.b = x;
+    return pair;
+}
+
+func int sum(int n) {
+    let Pair<int> pair = make(n);
+    return pair.a + pair.b;
+}
+        "#;
+        let (options, _) = parse_args(&["--lib".to_string()]).unwrap();
+        assert!(run_compiler_with(input, &options).is_ok());
+    }
+
+    #[test]
+    fn test_generic_type_errors() {
+        let input = r#"
+struct Pair<T> { T a, T b }
+struct Twice<T, T> { T a }
+struct Holder { Pair held }
+enumeration Color { Red, Green }
+
+func T max<T>(T a, T b) {
+    return a;
+}
+
+func T make<T>() {
+    let T value;
+    return value;
+}
+
+func int use(Pair ints, Pair<int, double> wrong, Color<int> color, Missing<int> missing) {
+    let int n = make();
+    let int m = max(1, true);
+    let Pair<int> p = ints;
+    return p.c;
+}
+        "#;
+        let (options, _) = parse_args(&["--lib".to_string()]).unwrap();
+        assert_eq!(
+            run_compiler_with(input, &options).unwrap_err(),
+            "Compilation error[G0015] at 3:17: 'T' is declared more than once\n  note: first declared at 3:14\n\
+             Compilation error[G0062] at 4:17: type 'Pair' takes 1 type argument(s) but 0 were given\n\
+             Compilation error[G0046] at 13:12: variable 'value' is read before a value is assigned to it on every path\n\
+             Compilation error[G0062] at 16:14: type 'Pair' takes 1 type argument(s) but 0 were given\n\
+             Compilation error[G0062] at 16:25: type 'Pair' takes 1 type argument(s) but 2 were given\n\
+             Compilation error[G0062] at 16:50: type 'Color' takes 0 type argument(s) but 1 were given\n\
+             Compilation error[G0008] at 16:68: undeclared type 'Missing'\n\
+             Compilation error[G0063] at 17:21: type parameter 'T' of 'make' cannot be inferred from the arguments\n\
+             Compilation error[G0027] at 18:24: argument 'b' of 'max' expects 'int', found 'boolean'\n\
+             Compilation error[G0038] at 20:14: structure 'Pair<int>' has no field 'c'; did you mean 'a'?"
+        );
+    }
+
+    #[test]
+    fn test_generic_instance_errors() {
+        let input = r#"
+struct Pair<T> { T a, T b }
+struct Shape { double w }
+
+func T max<T>(T a, T b) {
+    if (a > b) {
+        return a;
+    }
+    return b;
+}
+
+func void grow<T>(T value) {
+    let Pair<T> wrapped;
+    grow(wrapped);
+}
+
+func void compare(Shape a, Shape b) {
+    let Shape bigger = max(a, b);
+    let double widest = max(a.w, 1);
+    grow(widest);
+}
+
+func void main<T>() {
+}
+        "#;
+        // Each error in a generic function is reported once, however many instances have it.
+        assert_eq!(
+            run_compiler(input).unwrap_err(),
+            "Compilation error[G0035] at 6:11: operator '>' cannot be applied to 'Shape' and 'Shape'\n\
+             Compilation error[G0064] at 14:9: generic function 'grow' instantiates itself with ever larger types\n\
+             Compilation error[G0046] at 14:10: variable 'wrapped' is read before a value is assigned to it on every path\n\
+             Compilation error[G0054] at 23:1: 'main' must be declared as 'func int main()' or 'func void main()'"
+        );
+    }
+
+    #[test]
+    fn test_uncalled_generic_body() {
+        let input = r#"
+func T id<T>(T a) {
+    let int x = "str";
+    return undefined_thing;
+}
+        "#;
+        // Checked with `T` standing for any type, even though no instance of it exists.
+        let (options, _) = parse_args(&["--lib".to_string()]).unwrap();
+        assert_eq!(
+            run_compiler_with(input, &options).unwrap_err(),
+            "Compilation error[G0010] at 3:5: cannot assign 'string' to variable 'x' of type 'int'\n\
+             Compilation error[G0009] at 4:12: cannot find 'undefined_thing' in this scope"
+        );
+    }
+}